@@ -0,0 +1,157 @@
+// Compara el tiempo de validar una palabra larga con `DFA::run`, con `CompiledDfa::run` y, sobre un
+// alfabeto de bytes, con `DFA::run_bytes`; también el de validar muchas palabras cortas con
+// `DFA::run` y con `CompiledDfa::run`. Como referencia también se mide la representación anterior
+// del autómata, con nodos `Rc<RefCell>` y un `HashMap` de transiciones por estado. Con la
+// característica `bincode` se compara además la carga de un autómata grande desde texto y desde bytes.
+// Se ejecuta con `cargo bench` (o `cargo bench --features bincode`).
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use automata::{DfaData, DFA};
+
+const WORD_LENGTH: usize = 10_000_000;
+const ROUNDS: u32 = 5;
+const SHORT_WORDS: usize = 1_000_000;
+#[cfg(feature = "bincode")]
+const COUNTER_STATES: usize = 3000;
+
+// Mide el tiempo promedio de `ROUNDS` ejecuciones de `f`
+fn measure(name: &str, mut f: impl FnMut() -> bool) {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    let average: Duration = start.elapsed() / ROUNDS;
+    println!("{:<20} {:>10.2?}", name, average);
+}
+
+// Un estado de la representación anterior, que enlaza directamente con sus destinos
+struct RcNode {
+    is_accept: bool,
+    transitions: HashMap<char, Rc<RefCell<RcNode>>>,
+}
+
+// Reconstruye el autómata con la representación anterior y retorna su estado inicial
+fn rc_graph(dfa: &DFA) -> Rc<RefCell<RcNode>> {
+    let data = DfaData::from(dfa);
+    let nodes: HashMap<&str, Rc<RefCell<RcNode>>> = data
+        .states
+        .iter()
+        .map(|state| {
+            let node = RcNode {
+                is_accept: state.is_accept,
+                transitions: HashMap::new(),
+            };
+            (state.name.as_str(), Rc::new(RefCell::new(node)))
+        })
+        .collect();
+    for transition in &data.transitions {
+        nodes[transition.from.as_str()]
+            .borrow_mut()
+            .transitions
+            .insert(transition.symbol, Rc::clone(&nodes[transition.to.as_str()]));
+    }
+    Rc::clone(&nodes[data.start_state.as_str()])
+}
+
+// Recorre la representación anterior: un `borrow` y una búsqueda en el `HashMap` por símbolo
+fn rc_run(start: &Rc<RefCell<RcNode>>, input: &str) -> bool {
+    let mut current = Rc::clone(start);
+    for symbol in input.chars() {
+        let next = match current.borrow().transitions.get(&symbol) {
+            Some(next) => Rc::clone(next),
+            None => return false,
+        };
+        current = next;
+    }
+    let is_accept = current.borrow().is_accept;
+    is_accept
+}
+
+fn main() {
+    let dfa: DFA = "
+        alphabet={0,1}
+        state={q0, q1, q1q2, q2}
+        start_state=q0
+        F={q1q2, q2}
+        (q0, 1)->q1
+        (q0, 0)->q0
+        (q1, 1)->q1
+        (q1, 0)->q1q2
+        (q1q2, 0)->q1q2
+        (q1q2, 1)->q1q2
+        (q2, 0)->q2
+        (q2, 1)->q1q2
+    "
+    .parse()
+    .expect("el autómata de ejemplo es válido");
+    let compiled = dfa.compile();
+
+    let word: String = (0..WORD_LENGTH)
+        .map(|i| if i % 3 == 0 { '1' } else { '0' })
+        .collect();
+
+    let rc_start = rc_graph(&dfa);
+    measure("Rc<RefCell<Node>>", || rc_run(&rc_start, black_box(&word)));
+    measure("DFA::run", || dfa.run(black_box(&word)));
+    measure("CompiledDfa::run", || compiled.run(black_box(&word)));
+
+    // El mismo autómata sobre bytes, leído desde su descripción textual
+    let bytes_dfa = DFA::from_byte_string(&dfa.to_string_format())
+        .expect("el autómata de ejemplo es válido sobre bytes");
+    measure("DFA::run_bytes", || {
+        bytes_dfa.run_bytes(black_box(word.as_bytes()))
+    });
+
+    // Muchas palabras cortas, de 4 a 19 símbolos, tomadas de la palabra larga
+    let short_words: Vec<&str> = (0..SHORT_WORDS)
+        .map(|i| {
+            let start = (i * 7) % (WORD_LENGTH - 20);
+            &word[start..start + 4 + i % 16]
+        })
+        .collect();
+    println!("\n{} palabras cortas:", SHORT_WORDS);
+    measure("DFA::run", || {
+        short_words.iter().filter(|word| dfa.run(word)).count() > 0
+    });
+    measure("CompiledDfa::run", || {
+        short_words.iter().filter(|word| compiled.run(word)).count() > 0
+    });
+
+    #[cfg(feature = "bincode")]
+    measure_loading();
+}
+
+// Compara la carga de un contador módulo `COUNTER_STATES` desde texto y desde el formato binario
+#[cfg(feature = "bincode")]
+fn measure_loading() {
+    let names: Vec<String> = (0..COUNTER_STATES).map(|i| format!("s{}", i)).collect();
+    let mut text = format!(
+        "alphabet={{0,1}}\nstate={{{}}}\nstart_state=s0\nF={{s0}}\n",
+        names.join(",")
+    );
+    for i in 0..COUNTER_STATES {
+        let next = (i + 1) % COUNTER_STATES;
+        text.push_str(&format!("(s{}, 0)->s{}\n(s{}, 1)->s{}\n", i, i, i, next));
+    }
+    let bytes = DFA::from_string(&text)
+        .expect("el contador es válido")
+        .to_bytes();
+
+    println!(
+        "\nCarga de {} estados (texto: {} bytes, binario: {} bytes):",
+        COUNTER_STATES,
+        text.len(),
+        bytes.len()
+    );
+    measure("DFA::from_string", || {
+        DFA::from_string(black_box(&text)).is_ok()
+    });
+    measure("DFA::from_bytes", || {
+        DFA::<char>::from_bytes(black_box(&bytes)).is_ok()
+    });
+}
@@ -0,0 +1,435 @@
+// Funciones que leen el autómata desde la consola e imprimen sus partes.
+// Solo se compilan con la característica `cli`, porque esperan entrada del usuario.
+
+use std::collections::HashSet;
+use std::process::Command;
+
+use crate::{Node, RunResult, Symbol, DFA};
+
+impl DFA {
+    /**
+     Crea un autómata pidiendo al usuario el alfabeto, los estados, las transiciones y el estado inicial.
+        # Returns
+        Retorna el `DFA` definido por el usuario.
+    */
+    // Pide datos por consola, así que no tiene sentido como `Default`
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> DFA {
+        let mut dfa = DFA::with_alphabet(create_alphabet());
+        dfa.states = create_states(dfa.alphabet.len());
+        create_transitions(&mut dfa.states, &dfa.alphabet);
+        dfa.start_state = define_start_states(&mut dfa.states);
+        dfa
+    }
+}
+
+impl<S: Symbol> DFA<S> {
+    // Imprime el conjunto de estados
+    pub fn print_states(&self) {
+        print!("{}", self.format_states());
+    }
+
+    // Imprime el alfabeto
+    pub fn print_alphabet(&self) {
+        print!("{}", self.format_alphabet());
+    }
+
+    // Imprime el estado inicial
+    pub fn print_start_state(&self) {
+        print!("{}", self.format_start_state());
+    }
+
+    // Imprime el conjunto de estados de aceptación
+    pub fn print_accept_states(&self) {
+        print!("{}", self.format_accept_states());
+    }
+
+    // Imprime las transiciones del autómata
+    pub fn print_transitions(&self) {
+        print!("{}", self.format_transitions());
+    }
+
+    // Imprime la 5-tupla (Definición formal de un DFA)
+    pub fn tupla(&self) {
+        println!("{}", self.format_tupla());
+    }
+
+    // Imprime la 5-tupla e indica si faltan transiciones
+    pub fn tupla_with_diagnostics(&self) {
+        println!("{}", self.format_tupla_with_diagnostics());
+    }
+}
+
+/**
+ Función que crea el alfabeto del autómata.
+    # Returns
+    Retorna un `HashSet<char>` el cual representa mi conjunto de símbolos .
+*/
+fn create_alphabet() -> HashSet<char> {
+    let mut alphabet = HashSet::new();
+    let size: usize;
+    let mut input;
+
+    loop {
+        println!("Ingrese la cardinalidad del alfabeto: ");
+        input = String::new();
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        size = match input.trim().parse() {
+            Ok(size) => size,
+            Err(_) => {
+                println!("Error al leer la entrada.");
+                continue;
+            }
+        };
+        break;
+    }
+
+    let mut i = 0;
+
+    while i < size {
+        println!("Ingrese el símbolo {}: ", i + 1);
+        let mut input = String::new();
+
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue; // No incrementa i si hay error en la lectura
+        }
+
+        let symbol = match input.trim().chars().next() {
+            Some(c) => c,
+            None => {
+                println!("Entrada vacía, por favor ingrese un símbolo.");
+                continue; // No incrementa i si no se ingresa un símbolo
+            }
+        };
+
+        if alphabet.insert(symbol) {
+            i += 1; // Solo incrementa i si el símbolo es válido y no está duplicado
+        } else {
+            println!("El símbolo ya existe en el alfabeto.");
+            // No se incrementa i si el símbolo ya existe en el alfabeto
+        }
+    }
+    alphabet
+}
+
+/**
+ Función que crea mi conjunto de estados del autómata .
+    # Arguments
+    * `symbols` - La cantidad de símbolos del alfabeto.
+    # Returns
+    Retorna un `Vec<Node>` el cual representa mi conjunto de estados.
+*/
+fn create_states(symbols: usize) -> Vec<Node> {
+    let mut states = Vec::new();
+    let mut states_name: HashSet<String> = HashSet::new();
+    let mut input;
+
+    loop {
+        println!("Ingrese la cardinalidad del conjunto de los estados: ");
+        input = String::new();
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        let size = match input.trim().parse() {
+            Ok(size) => size,
+            Err(_) => {
+                println!("Error al leer la entrada.");
+                continue;
+            }
+        };
+
+        let mut i: usize = 0;
+
+        while i < size {
+            println!("Ingrese el nombre del estado {}: ", i);
+            input = String::new();
+
+            if std::io::stdin().read_line(&mut input).is_err() {
+                println!("Error al leer la entrada.");
+                continue;
+            }
+
+            let state = input.trim().to_string();
+
+            if !states_name.insert(state.clone()) {
+                println!("El estado \"{}\" ya ha sido definido.", state);
+                continue;
+            }
+
+            let is_accept;
+
+            loop {
+                println!("Es estado de aceptacion? (s/n): ");
+                input = String::new();
+
+                if std::io::stdin().read_line(&mut input).is_err() {
+                    println!("Error al leer la entrada.");
+                    continue;
+                }
+
+                match input.trim() {
+                    "s" => {
+                        is_accept = true;
+                        break;
+                    }
+                    "n" => {
+                        is_accept = false;
+                        break;
+                    }
+                    _ => {
+                        println!("Opcion invalida.");
+                    }
+                }
+            }
+
+            states.push(Node::new(&state, is_accept, symbols));
+            i += 1;
+        }
+
+        break;
+    }
+
+    states
+}
+
+/**
+ Crea las transiciones entre los nodos (estados) del autómata.
+    # Arguments
+    * `states` - Referencia al vector de nodos.
+    * `alphabet` - Referencia al alfabeto ordenado.
+*/
+fn create_transitions(states: &mut [Node], alphabet: &[char]) {
+    for i in 0..states.len() {
+        for (symbol_index, symbol) in alphabet.iter().enumerate() {
+            let mut input;
+
+            loop {
+                println!(
+                    "Ingrese el estado al que se transiciona \"{}\" con el símbolo {}: ",
+                    states[i].state, symbol
+                );
+                input = String::new();
+
+                if std::io::stdin().read_line(&mut input).is_err() {
+                    println!("Error al leer la entrada.");
+                    continue;
+                }
+
+                let next_state = states.iter().position(|x| x.state == input.trim());
+
+                match next_state {
+                    Some(next) => {
+                        states[i].transitions[symbol_index] = Some(next);
+                        break;
+                    }
+                    None => {
+                        println!("El estado no existe.");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+ Define mi estado inicial del autómata.
+    # Arguments
+    * `states` - Referencia al vector de nodos.
+    # Returns
+    Retorna la posición del estado inicial. Si no hay estados se agrega uno llamado `Empty`.
+*/
+fn define_start_states(states: &mut Vec<Node>) -> usize {
+    let mut input;
+
+    if states.is_empty() {
+        states.push(Node::new("Empty", false, 0));
+        return 0;
+    }
+
+    loop {
+        println!("Ingrese el estado inicial: ");
+        input = String::new();
+
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        let start_state = states.iter().position(|x| x.state == input.trim());
+
+        match start_state {
+            Some(start) => {
+                return start;
+            }
+            None => {
+                println!("El estado no existe.");
+            }
+        }
+    }
+}
+
+// Menú principal del programa.
+pub fn menu() {
+    clear_console();
+    println!("Cree un autómata finito determinista.\n");
+    let mut dfa = DFA::new();
+    print_warnings(&dfa);
+
+    loop {
+        wait_for_keypress();
+        clear_console();
+        println!("Autómata Finito Determinista");
+        println!("=============================");
+        println!("1. Crear o reemplazar un nuevo autómata.");
+        println!("2. Validar una palabra.");
+        println!("3. Imprimir el conjunto de estados.");
+        println!("4. Imprimir el alfabeto.");
+        println!("5. Imprimir el estado inicial.");
+        println!("6. Imprimir los conjuntos de aceptación.");
+        println!("7. Imprimir las 5-tupla.");
+        println!("8. Imprimir las transiciones.");
+        println!("9. Depurar una palabra paso a paso.");
+        println!("10. Salir del programa.\n");
+
+        let mut choice = String::new();
+        std::io::stdin().read_line(&mut choice).unwrap();
+
+        match choice.trim() {
+            "1" => {
+                dfa = DFA::new();
+                println!("Nuevo autómata creado.");
+                print_warnings(&dfa);
+            }
+            "2" => {
+                println!("Ingrese la palabra a validar:");
+                let mut input = String::new();
+                std::io::stdin().read_line(&mut input).unwrap();
+                let trace = dfa.run_trace(input.trim());
+                if let Some(message) = trace.rejection_message() {
+                    println!("{}", message);
+                }
+                if trace.accepted() {
+                    println!("La palabra es aceptada por el autómata.");
+                } else {
+                    println!("La palabra es rechazada por el autómata.");
+                }
+            }
+            "3" => {
+                println!("Conjunto de estados:");
+                dfa.print_states();
+                println!();
+            }
+            "4" => {
+                println!("Alfabeto:");
+                dfa.print_alphabet();
+                println!();
+            }
+            "5" => {
+                println!("Estado inicial:");
+                dfa.print_start_state();
+                println!();
+            }
+            "6" => {
+                println!("Conjuntos de aceptación:");
+                dfa.print_accept_states();
+                println!();
+            }
+            "7" => {
+                println!("Conjuntos de aceptación:");
+                dfa.tupla();
+            }
+            "8" => {
+                println!("Transiciones:");
+                dfa.print_transitions();
+            }
+            "9" => {
+                println!("Ingrese la palabra a depurar:");
+                let mut input = String::new();
+                std::io::stdin().read_line(&mut input).unwrap();
+                debug_word(&dfa, input.trim());
+            }
+            "10" => break,
+            _ => println!("Opción no válida, intente de nuevo."),
+        }
+    }
+}
+
+// Imprime las advertencias sobre estados inalcanzables o muertos del autómata
+fn print_warnings(dfa: &DFA) {
+    for warning in dfa.warnings() {
+        println!("Advertencia: {}", warning);
+    }
+}
+
+/**
+ Recorre una palabra símbolo por símbolo, esperando a que el usuario presione enter antes de cada paso.
+ En cada paso se muestra el estado actual, el símbolo que se va a consumir y el estado al que se llega,
+ indicando cuando se entra o se sale de un estado de aceptación.
+    # Arguments
+    * `dfa` - El autómata que se ejecuta.
+    * `input` - La palabra a depurar.
+*/
+fn debug_word(dfa: &DFA, input: &str) {
+    let trace = dfa.run_trace(input);
+    let is_accept = |state: &str| dfa.is_accepting_state(state).unwrap_or(false);
+
+    println!("Estado inicial: {}", trace.states[0]);
+    for (i, symbol) in trace.symbols.iter().enumerate() {
+        wait_for_keypress();
+        let (from, to) = (&trace.states[i], &trace.states[i + 1]);
+        println!("Paso {}: {} --{}--> {}", i + 1, from, symbol, to);
+        match (is_accept(from), is_accept(to)) {
+            (false, true) => println!("  Entra al estado de aceptación {}.", to),
+            (true, false) => println!("  Sale del estado de aceptación {}.", from),
+            _ => {}
+        }
+    }
+
+    if let Some(symbol) = &trace.halted_on {
+        wait_for_keypress();
+        match trace.result {
+            RunResult::InvalidSymbol(_) => {
+                println!("El símbolo '{}' no pertenece al alfabeto.", symbol)
+            }
+            _ => println!(
+                "No hay transición desde {} con el símbolo '{}'.",
+                trace.final_state(),
+                symbol
+            ),
+        }
+    }
+
+    if trace.accepted() {
+        println!("La palabra es aceptada por el autómata.");
+    } else {
+        println!("La palabra es rechazada por el autómata.");
+    }
+}
+
+// Función para limpiar la consola
+fn clear_console() {
+    if cfg!(target_os = "windows") {
+        Command::new("cmd")
+            .args(["/C", "cls"])
+            .status()
+            .expect("Error al limpiar la consola");
+    } else {
+        Command::new("clear")
+            .status()
+            .expect("Error al limpiar la consola");
+    }
+}
+
+// Función para esperar a que el usuario presione una tecla
+fn wait_for_keypress() {
+    let mut input = String::new();
+    println!("Presione enter para continuar...");
+    std::io::stdin().read_line(&mut input).ok();
+}
@@ -0,0 +1,6618 @@
+//! Autómatas finitos deterministas: lectura desde texto, ejecución sobre palabras, minimización,
+//! operaciones entre autómatas y conversión desde y hacia expresiones regulares.
+//!
+//! ```
+//! let dfa: automata::DFA = "alphabet={0,1}\nstate={par, impar}\nstart_state=par\nF={par}\n\
+//!     (par, 0)->par\n(par, 1)->impar\n(impar, 0)->impar\n(impar, 1)->par"
+//!     .parse()
+//!     .unwrap();
+//! assert!(dfa.accepts("0110".chars()));
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::io::{self, BufRead, BufReader, Read};
+use std::str::FromStr;
+
+// Menú y lectura interactiva del autómata desde la consola.
+#[cfg(feature = "cli")]
+pub mod interactive;
+// Enlaces para Python.
+#[cfg(feature = "pyo3")]
+pub mod python;
+// Enlaces para WebAssembly.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod xml;
+
+// Símbolos que puede leer un autómata: caracteres sueltos o tokens de varios caracteres.
+pub trait Symbol: Clone + Eq + Hash + Ord + fmt::Display + fmt::Debug {}
+
+impl<T: Clone + Eq + Hash + Ord + fmt::Display + fmt::Debug> Symbol for T {}
+
+// Los estados se guardan en un vector y se referencian por su posición, por lo que
+// `clone` produce una copia independiente del autómata.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
+pub struct DFA<S = char> {
+    states: Vec<Node>,
+    // Alfabeto ordenado y sin repetidos; la posición de cada símbolo indexa las transiciones.
+    alphabet: Vec<S>,
+    start_state: usize,
+}
+
+#[derive(Clone)]
+struct Node {
+    state: String,
+    is_accept: bool,
+    // `transitions[i]` es el estado destino al leer `alphabet[i]`, si está definido.
+    transitions: Vec<Option<usize>>,
+}
+
+// Autómata finito no determinista; los estados se identifican por su nombre.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone)]
+pub struct NFA<S = char> {
+    states: Vec<String>,
+    alphabet: HashSet<S>,
+    start_states: HashSet<String>,
+    accept_states: HashSet<String>,
+    transitions: HashMap<String, HashMap<S, HashSet<String>>>,
+    // Transiciones que no consumen ningún símbolo.
+    epsilon_transitions: HashMap<String, HashSet<String>>,
+}
+
+// Errores que pueden surgir al leer la descripción textual de un autómata.
+#[derive(Debug)]
+pub enum DfaParseError<S = char> {
+    // La línea de transición no contiene el separador "->".
+    MissingArrow {
+        line: usize,
+        text: String,
+    },
+    // La línea de transición no tiene la forma "(estado, símbolo)->estado".
+    MalformedTransition {
+        line: usize,
+        text: String,
+    },
+    // El símbolo de la transición no es exactamente un símbolo, por ejemplo `ab` en un alfabeto de caracteres.
+    MalformedSymbol {
+        line: usize,
+        text: String,
+    },
+    // La transición hace referencia a un estado que no fue declarado.
+    UnknownState {
+        line: usize,
+        name: String,
+    },
+    // La transición usa un símbolo que no pertenece al alfabeto.
+    UnknownSymbol {
+        line: usize,
+        symbol: S,
+    },
+    // El par (estado, símbolo) ya tenía una transición definida.
+    DuplicateTransition {
+        line: usize,
+        state: String,
+        symbol: S,
+    },
+    // Un estado tiene más de una transición comodín `(estado, *)->destino`.
+    DuplicateWildcard {
+        line: usize,
+        state: String,
+    },
+    // El mismo estado aparece dos veces en `state=`.
+    DuplicateState {
+        line: usize,
+        name: String,
+    },
+    // La descripción no tiene una línea `start_state=`.
+    MissingStartState,
+    // El estado inicial no fue declarado en `state=`.
+    UnknownStartState {
+        line: usize,
+        name: String,
+    },
+    // La línea `F=` no tiene una lista de estados, `{}` ni `*`.
+    MalformedAcceptStates {
+        line: usize,
+        text: String,
+    },
+    // Uno o más estados de `F=` no fueron declarados en `state=`; `line` es la línea del primero.
+    UnknownAcceptStates {
+        line: usize,
+        names: Vec<String>,
+    },
+    // La transición de una máquina de Mealy no indica su salida con "/ salida".
+    MissingOutput {
+        line: usize,
+        text: String,
+    },
+    // La línea no es un comentario ni empieza con un prefijo conocido.
+    UnrecognizedLine {
+        line: usize,
+        text: String,
+    },
+}
+
+impl<S: Symbol> fmt::Display for DfaParseError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DfaParseError::MissingArrow { line, text } => {
+                write!(
+                    f,
+                    "Línea {}: falta el separador \"->\" en \"{}\"",
+                    line, text
+                )
+            }
+            DfaParseError::MalformedTransition { line, text } => {
+                write!(f, "Línea {}: transición mal formada \"{}\"", line, text)
+            }
+            DfaParseError::MalformedSymbol { line, text } => {
+                write!(
+                    f,
+                    "Línea {}: \"{}\" no es un único símbolo; cada transición lee exactamente uno",
+                    line, text
+                )
+            }
+            DfaParseError::UnknownState { line, name } => {
+                write!(f, "Línea {}: el estado \"{}\" no existe", line, name)
+            }
+            DfaParseError::UnknownSymbol { line, symbol } => {
+                write!(
+                    f,
+                    "Línea {}: el símbolo '{}' no pertenece al alfabeto",
+                    line, symbol
+                )
+            }
+            DfaParseError::DuplicateTransition {
+                line,
+                state,
+                symbol,
+            } => write!(
+                f,
+                "Línea {}: la transición ({}, {}) ya fue definida",
+                line, state, symbol
+            ),
+            DfaParseError::DuplicateWildcard { line, state } => write!(
+                f,
+                "Línea {}: el estado \"{}\" ya tiene una transición comodín",
+                line, state
+            ),
+            DfaParseError::DuplicateState { line, name } => {
+                write!(
+                    f,
+                    "Línea {}: el estado \"{}\" ya fue declarado",
+                    line, name
+                )
+            }
+            DfaParseError::MissingStartState => {
+                write!(f, "Falta la línea start_state= con el estado inicial")
+            }
+            DfaParseError::UnknownStartState { line, name } => {
+                write!(
+                    f,
+                    "Línea {}: el estado inicial \"{}\" no existe",
+                    line, name
+                )
+            }
+            DfaParseError::MalformedAcceptStates { line, text } => write!(
+                f,
+                "Línea {}: \"{}\" no indica los estados de aceptación; use una lista como {{q1, q2}}, {{}} para ninguno o * para todos",
+                line, text
+            ),
+            DfaParseError::UnknownAcceptStates { line, names } => {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                if names.len() == 1 {
+                    write!(
+                        f,
+                        "Línea {}: el estado de aceptación {} no existe",
+                        line, names[0]
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Línea {}: los estados de aceptación {} no existen",
+                        line,
+                        names.join(", ")
+                    )
+                }
+            }
+            DfaParseError::MissingOutput { line, text } => {
+                write!(
+                    f,
+                    "Línea {}: falta la salida \"/ salida\" en \"{}\"",
+                    line, text
+                )
+            }
+            DfaParseError::UnrecognizedLine { line, text } => {
+                write!(f, "Línea {}: no se reconoce \"{}\"", line, text)
+            }
+        }
+    }
+}
+
+impl<S: Symbol> std::error::Error for DfaParseError<S> {}
+
+// Errores que pueden surgir al construir o modificar un autómata.
+#[derive(Debug)]
+pub enum DfaError<S = char> {
+    // Error al leer la descripción textual.
+    Parse(DfaParseError<S>),
+    // Se hace referencia a un estado que no existe.
+    UnknownState(String),
+    // Se usa un símbolo que no pertenece al alfabeto.
+    UnknownSymbol(S),
+    // Se declaró dos veces un estado con el mismo nombre.
+    DuplicateState(String),
+    // El par (estado, símbolo) ya tenía una transición definida.
+    DuplicateTransition { state: String, symbol: S },
+    // No se definió el estado inicial.
+    MissingStartState,
+    // Una fila de la tabla CSV no tiene la forma esperada.
+    MalformedCsv { line: usize, text: String },
+    // El documento XML está mal formado o no describe un autómata finito.
+    MalformedXml(String),
+    // El archivo importado tiene una transición que no consume símbolos.
+    EpsilonTransition { from: String, to: String },
+    // Los bytes no contienen un autómata serializado con `to_bytes`.
+    MalformedBinary(String),
+    // Se intentó eliminar el estado inicial.
+    CannotRemoveStartState(String),
+    // Se pidió el producto de una lista vacía de autómatas.
+    EmptyProduct,
+}
+
+impl<S: Symbol> fmt::Display for DfaError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DfaError::Parse(error) => write!(f, "{}", error),
+            DfaError::UnknownState(name) => write!(f, "El estado \"{}\" no existe", name),
+            DfaError::UnknownSymbol(symbol) => {
+                write!(f, "El símbolo '{}' no pertenece al alfabeto", symbol)
+            }
+            DfaError::DuplicateState(name) => {
+                write!(f, "El estado \"{}\" ya ha sido definido", name)
+            }
+            DfaError::DuplicateTransition { state, symbol } => {
+                write!(f, "La transición ({}, {}) ya fue definida", state, symbol)
+            }
+            DfaError::MissingStartState => write!(f, "No se definió el estado inicial"),
+            DfaError::MalformedCsv { line, text } => {
+                write!(f, "Línea {}: fila CSV mal formada \"{}\"", line, text)
+            }
+            DfaError::MalformedXml(message) => write!(f, "XML inválido: {}", message),
+            DfaError::CannotRemoveStartState(name) => {
+                write!(f, "No se puede eliminar el estado inicial \"{}\"", name)
+            }
+            DfaError::MalformedBinary(message) => {
+                write!(f, "Formato binario inválido: {}", message)
+            }
+            DfaError::EmptyProduct => write!(f, "El producto necesita al menos un autómata"),
+            DfaError::EpsilonTransition { from, to } => write!(
+                f,
+                "La transición de \"{}\" a \"{}\" no consume símbolos; un DFA no admite transiciones épsilon",
+                from, to
+            ),
+        }
+    }
+}
+
+impl<S: Symbol> std::error::Error for DfaError<S> {}
+
+impl<S> From<DfaParseError<S>> for DfaError<S> {
+    fn from(error: DfaParseError<S>) -> Self {
+        DfaError::Parse(error)
+    }
+}
+
+// Advertencias sobre un autómata válido que probablemente tiene un error de modelado.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DfaWarning {
+    // Ningún camino desde el estado inicial llega a este estado.
+    UnreachableState(String),
+    // Desde este estado no se puede llegar a ningún estado de aceptación.
+    DeadState(String),
+}
+
+impl fmt::Display for DfaWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DfaWarning::UnreachableState(name) => {
+                write!(
+                    f,
+                    "El estado \"{}\" no es alcanzable desde el estado inicial",
+                    name
+                )
+            }
+            DfaWarning::DeadState(name) => write!(
+                f,
+                "Desde el estado \"{}\" no se puede llegar a ningún estado de aceptación",
+                name
+            ),
+        }
+    }
+}
+
+// Errores que pueden surgir al leer una expresión regular.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegexError {
+    // Un paréntesis no tiene su pareja.
+    UnbalancedParenthesis { position: usize },
+    // Un operador no tiene una expresión a la cual aplicarse.
+    MissingOperand { position: usize, operator: char },
+    // La expresión termina con una barra invertida sin escapar nada.
+    TrailingEscape,
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegexError::UnbalancedParenthesis { position } => {
+                write!(f, "Posición {}: paréntesis sin pareja", position)
+            }
+            RegexError::MissingOperand { position, operator } => write!(
+                f,
+                "Posición {}: el operador '{}' no tiene operando",
+                position, operator
+            ),
+            RegexError::TrailingEscape => {
+                write!(f, "La expresión termina con una barra invertida")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+// Árbol sintáctico de una expresión regular.
+#[derive(Debug, Clone, PartialEq)]
+enum Regex {
+    // El lenguaje vacío, que no acepta ninguna palabra.
+    Nothing,
+    // La palabra vacía.
+    Empty,
+    Symbol(char),
+    Concat(Box<Regex>, Box<Regex>),
+    Union(Box<Regex>, Box<Regex>),
+    Star(Box<Regex>),
+    Plus(Box<Regex>),
+    Optional(Box<Regex>),
+}
+
+impl Regex {
+    // Concatena simplificando la palabra vacía y el lenguaje vacío
+    fn concat(left: Regex, right: Regex) -> Regex {
+        match (left, right) {
+            (Regex::Nothing, _) | (_, Regex::Nothing) => Regex::Nothing,
+            (Regex::Empty, other) | (other, Regex::Empty) => other,
+            (left, right) => Regex::Concat(Box::new(left), Box::new(right)),
+        }
+    }
+
+    // Une simplificando el lenguaje vacío y las alternativas repetidas
+    fn union(left: Regex, right: Regex) -> Regex {
+        match (left, right) {
+            (Regex::Nothing, other) | (other, Regex::Nothing) => other,
+            (left, right) if left == right => left,
+            (left, right) => Regex::Union(Box::new(left), Box::new(right)),
+        }
+    }
+
+    // Aplica la estrella de Kleene simplificando los casos triviales
+    fn star(inner: Regex) -> Regex {
+        match inner {
+            Regex::Nothing | Regex::Empty => Regex::Empty,
+            Regex::Star(inner) => Regex::Star(inner),
+            inner => Regex::Star(Box::new(inner)),
+        }
+    }
+
+    // Escribe la expresión agregando paréntesis solo cuando la precedencia lo requiere
+    fn write_with_precedence(&self, f: &mut fmt::Formatter, precedence: u8) -> fmt::Result {
+        let (own, needs_parens) = match self {
+            Regex::Union(..) => (0, precedence > 0),
+            Regex::Concat(..) => (1, precedence > 1),
+            _ => (2, false),
+        };
+        if needs_parens {
+            write!(f, "(")?;
+        }
+        match self {
+            Regex::Nothing => write!(f, "∅")?,
+            Regex::Empty => write!(f, "()")?,
+            Regex::Symbol(c) => {
+                if "|*+?()\\∅".contains(*c) {
+                    write!(f, "\\")?;
+                }
+                write!(f, "{}", c)?;
+            }
+            Regex::Union(left, right) => {
+                left.write_with_precedence(f, own)?;
+                write!(f, "|")?;
+                right.write_with_precedence(f, own)?;
+            }
+            Regex::Concat(left, right) => {
+                left.write_with_precedence(f, own)?;
+                right.write_with_precedence(f, own)?;
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => {
+                inner.write_with_precedence(f, own)?;
+                let operator = match self {
+                    Regex::Star(_) => '*',
+                    Regex::Plus(_) => '+',
+                    _ => '?',
+                };
+                write!(f, "{}", operator)?;
+            }
+        }
+        if needs_parens {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Regex {
+    // Escribe la expresión con la misma sintaxis que lee `RegexParser`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_with_precedence(f, 0)
+    }
+}
+
+// Analizador descendente recursivo de expresiones regulares.
+struct RegexParser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl RegexParser {
+    /**
+     Lee una expresión regular completa.
+        # Arguments
+        * `pattern` - La expresión. Los operadores son `|*+?()`, `∅` es el lenguaje vacío y `\` escapa al siguiente carácter.
+        # Returns
+        Retorna el árbol de la expresión, o un `RegexError` si está mal formada.
+    */
+    fn parse(pattern: &str) -> Result<Regex, RegexError> {
+        let mut parser = RegexParser {
+            chars: pattern.chars().collect(),
+            position: 0,
+        };
+        let regex = parser.parse_union()?;
+        if parser.position < parser.chars.len() {
+            // Lo único que detiene a `parse_union` antes del final es un ')' sin abrir
+            return Err(RegexError::UnbalancedParenthesis {
+                position: parser.position,
+            });
+        }
+        Ok(regex)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    // union := concat ('|' concat)*
+    fn parse_union(&mut self) -> Result<Regex, RegexError> {
+        let mut regex = self.parse_concat()?;
+        while self.peek() == Some('|') {
+            self.position += 1;
+            let right = self.parse_concat()?;
+            regex = Regex::Union(Box::new(regex), Box::new(right));
+        }
+        Ok(regex)
+    }
+
+    // concat := repeat*; una concatenación vacía representa la palabra vacía
+    fn parse_concat(&mut self) -> Result<Regex, RegexError> {
+        let mut regex: Option<Regex> = None;
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let next = self.parse_repeat()?;
+            regex = Some(match regex {
+                Some(left) => Regex::Concat(Box::new(left), Box::new(next)),
+                None => next,
+            });
+        }
+        Ok(regex.unwrap_or(Regex::Empty))
+    }
+
+    // repeat := atom ('*' | '+' | '?')*
+    fn parse_repeat(&mut self) -> Result<Regex, RegexError> {
+        let mut regex = self.parse_atom()?;
+        while let Some(c) = self.peek() {
+            regex = match c {
+                '*' => Regex::Star(Box::new(regex)),
+                '+' => Regex::Plus(Box::new(regex)),
+                '?' => Regex::Optional(Box::new(regex)),
+                _ => break,
+            };
+            self.position += 1;
+        }
+        Ok(regex)
+    }
+
+    // atom := '(' union ')' | '∅' | '\' carácter | carácter
+    fn parse_atom(&mut self) -> Result<Regex, RegexError> {
+        let position = self.position;
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Ok(Regex::Empty),
+        };
+        self.position += 1;
+
+        match c {
+            '(' => {
+                let regex = self.parse_union()?;
+                if self.peek() != Some(')') {
+                    return Err(RegexError::UnbalancedParenthesis { position });
+                }
+                self.position += 1;
+                Ok(regex)
+            }
+            '*' | '+' | '?' => Err(RegexError::MissingOperand {
+                position,
+                operator: c,
+            }),
+            '∅' => Ok(Regex::Nothing),
+            '\\' => {
+                let escaped = self.peek().ok_or(RegexError::TrailingEscape)?;
+                self.position += 1;
+                Ok(Regex::Symbol(escaped))
+            }
+            _ => Ok(Regex::Symbol(c)),
+        }
+    }
+}
+
+// Representación plana de un autómata, sin punteros compartidos, útil para serializarlo.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DfaData<S = char> {
+    pub alphabet: Vec<S>,
+    pub states: Vec<StateData>,
+    pub start_state: String,
+    pub transitions: Vec<TransitionData<S>>,
+}
+
+// Un estado dentro de `DfaData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateData {
+    pub name: String,
+    pub is_accept: bool,
+}
+
+// Una transición dentro de `DfaData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransitionData<S = char> {
+    pub from: String,
+    pub symbol: S,
+    pub to: String,
+}
+
+// Permite construir un autómata desde código encadenando llamadas.
+// Para alfabetos de tokens se crea con `DfaBuilder::<String>::default()`.
+#[derive(Debug, Default)]
+pub struct DfaBuilder<S = char> {
+    alphabet: Vec<S>,
+    states: Vec<StateData>,
+    start_state: Option<String>,
+    transitions: Vec<TransitionData<S>>,
+}
+
+impl DfaBuilder {
+    pub fn new() -> DfaBuilder {
+        DfaBuilder::default()
+    }
+}
+
+impl<S: Symbol> DfaBuilder<S> {
+    // Agrega un símbolo al alfabeto aunque no aparezca en ninguna transición
+    pub fn add_symbol(&mut self, symbol: S) -> &mut DfaBuilder<S> {
+        if !self.alphabet.contains(&symbol) {
+            self.alphabet.push(symbol);
+        }
+        self
+    }
+
+    // Agrega un estado al autómata
+    pub fn add_state(&mut self, name: &str, is_accept: bool) -> &mut DfaBuilder<S> {
+        self.states.push(StateData {
+            name: name.to_string(),
+            is_accept,
+        });
+        self
+    }
+
+    // Agrega una transición; su símbolo se incorpora al alfabeto
+    pub fn add_transition(&mut self, from: &str, symbol: S, to: &str) -> &mut DfaBuilder<S> {
+        self.add_symbol(symbol.clone());
+        self.transitions.push(TransitionData {
+            from: from.to_string(),
+            symbol,
+            to: to.to_string(),
+        });
+        self
+    }
+
+    // Define el estado inicial
+    pub fn set_start(&mut self, name: &str) -> &mut DfaBuilder<S> {
+        self.start_state = Some(name.to_string());
+        self
+    }
+
+    /**
+     Construye el autómata validando los estados y las transiciones declaradas.
+        # Returns
+        Retorna un `DfaError` si falta el estado inicial o alguna transición hace referencia a un
+        estado desconocido.
+    */
+    pub fn build(&self) -> Result<DFA<S>, DfaError<S>> {
+        let start_state = self
+            .start_state
+            .clone()
+            .ok_or(DfaError::MissingStartState)?;
+
+        DFA::from_data(DfaData {
+            alphabet: self.alphabet.clone(),
+            states: self.states.clone(),
+            start_state,
+            transitions: self.transitions.clone(),
+        })
+    }
+}
+
+impl<S: Symbol> From<&DFA<S>> for DfaData<S> {
+    fn from(dfa: &DFA<S>) -> Self {
+        let states = dfa
+            .states
+            .iter()
+            .map(|state| StateData {
+                name: state.state.clone(),
+                is_accept: state.is_accept,
+            })
+            .collect();
+
+        let mut transitions = Vec::new();
+        for state in &dfa.states {
+            for (symbol, next) in dfa.alphabet.iter().zip(&state.transitions) {
+                if let Some(next) = next {
+                    transitions.push(TransitionData {
+                        from: state.state.clone(),
+                        symbol: symbol.clone(),
+                        to: dfa.states[*next].state.clone(),
+                    });
+                }
+            }
+        }
+
+        DfaData {
+            alphabet: dfa.alphabet.clone(),
+            states,
+            start_state: dfa.start_name().to_string(),
+            transitions,
+        }
+    }
+}
+
+// Forma binaria del autómata: las transiciones se guardan como una tabla de índices, fila por estado,
+// en lugar de repetir los nombres como en `DfaData`.
+#[cfg(feature = "bincode")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BinaryDfa<S> {
+    alphabet: Vec<S>,
+    states: Vec<StateData>,
+    start_state: u32,
+    transitions: Vec<Option<u32>>,
+}
+
+#[cfg(feature = "bincode")]
+impl<S: Symbol + serde::Serialize + serde::de::DeserializeOwned> DFA<S> {
+    /**
+     Serializa el autómata en un formato binario compacto con bincode, que ocupa menos y se carga mucho
+     más rápido que la descripción textual.
+        # Returns
+        Retorna los bytes que lee `from_bytes`.
+    */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        use bincode::Options;
+
+        let binary = BinaryDfa {
+            alphabet: self.alphabet.clone(),
+            states: self
+                .states
+                .iter()
+                .map(|state| StateData {
+                    name: state.state.clone(),
+                    is_accept: state.is_accept,
+                })
+                .collect(),
+            start_state: self.start_state as u32,
+            transitions: self
+                .states
+                .iter()
+                .flat_map(|state| state.transitions.iter())
+                .map(|next| next.map(|next| next as u32))
+                .collect(),
+        };
+        bincode::options()
+            .serialize(&binary)
+            .expect("el autómata siempre se puede serializar")
+    }
+
+    /**
+     Reconstruye un autómata guardado con `to_bytes`.
+        # Arguments
+        * `data` - Los bytes serializados.
+        # Returns
+        Retorna un `DfaError::MalformedBinary` si los bytes no se pueden leer o la tabla de transiciones
+        no corresponde a los estados, o los mismos errores que `from_data` si describen un autómata inválido.
+    */
+    pub fn from_bytes(data: &[u8]) -> Result<DFA<S>, DfaError<S>> {
+        use bincode::Options;
+
+        let binary: BinaryDfa<S> = bincode::options()
+            .deserialize(data)
+            .map_err(|error| DfaError::MalformedBinary(error.to_string()))?;
+
+        let name = |index: u32| {
+            binary
+                .states
+                .get(index as usize)
+                .map(|state| state.name.clone())
+                .ok_or_else(|| DfaError::MalformedBinary(format!("el estado {} no existe", index)))
+        };
+        if binary.transitions.len() != binary.states.len() * binary.alphabet.len() {
+            return Err(DfaError::MalformedBinary(
+                "la tabla de transiciones no tiene una fila por estado".to_string(),
+            ));
+        }
+
+        // Se reconstruye a través de `DfaData` para reutilizar sus validaciones
+        let mut transitions = Vec::new();
+        for (position, next) in binary.transitions.iter().enumerate() {
+            if let Some(next) = *next {
+                let (from, symbol) = (
+                    position / binary.alphabet.len(),
+                    position % binary.alphabet.len(),
+                );
+                transitions.push(TransitionData {
+                    from: name(from as u32)?,
+                    symbol: binary.alphabet[symbol].clone(),
+                    to: name(next)?,
+                });
+            }
+        }
+        DFA::from_data(DfaData {
+            start_state: name(binary.start_state)?,
+            alphabet: binary.alphabet,
+            states: binary.states,
+            transitions,
+        })
+    }
+}
+
+// Tabla de transiciones plana para validar muchas palabras sin búsquedas por nombre ni hashing.
+#[derive(Debug, Clone)]
+pub struct CompiledDfa {
+    // `table[estado * symbols + símbolo]` es el estado destino, o -1 si no hay transición.
+    table: Vec<i32>,
+    symbols: usize,
+    // Posición de cada carácter ASCII en el alfabeto, o -1 si no pertenece.
+    ascii_index: [i32; 128],
+    // Resto del alfabeto, ordenado para buscarlo por bisección.
+    other_symbols: Vec<(char, usize)>,
+    // Un bit por estado, encendido si el estado es de aceptación.
+    accepting: Vec<u64>,
+    start_state: i32,
+}
+
+impl CompiledDfa {
+    // Retorna la posición de un carácter en el alfabeto
+    fn symbol_index(&self, symbol: char) -> Option<usize> {
+        if symbol.is_ascii() {
+            let index = self.ascii_index[symbol as usize];
+            return (index >= 0).then_some(index as usize);
+        }
+        self.other_symbols
+            .binary_search_by_key(&symbol, |(c, _)| *c)
+            .ok()
+            .map(|position| self.other_symbols[position].1)
+    }
+
+    /**
+     Valida si la palabra es aceptada, sin imprimir mensajes.
+        # Arguments
+        * `input` - La palabra a analizar.
+        # Returns
+        Retorna `true` si la palabra termina en un estado de aceptación.
+    */
+    pub fn run(&self, input: &str) -> bool {
+        let mut state = self.start_state;
+        for symbol in input.chars() {
+            let symbol = match self.symbol_index(symbol) {
+                Some(symbol) => symbol,
+                None => return false,
+            };
+            state = self.table[state as usize * self.symbols + symbol];
+            if state < 0 {
+                return false;
+            }
+        }
+        self.accepting[state as usize / 64] & (1 << (state as usize % 64)) != 0
+    }
+}
+
+// Máquina de Mealy: un autómata cuyas transiciones emiten una salida al ser recorridas.
+#[derive(Clone)]
+pub struct MealyMachine<S = char> {
+    // Estados y transiciones; la aceptación de los estados no se usa.
+    automaton: DFA<S>,
+    // `outputs[q][i]` es la salida de la transición de `q` con el símbolo `i` del alfabeto.
+    outputs: Vec<Vec<Option<String>>>,
+}
+
+// Iterador que enumera las palabras aceptadas por un autómata en orden por longitud y luego lexicográfico.
+pub struct LanguageIter<'a> {
+    dfa: &'a DFA,
+    // Estados desde los que todavía se puede llegar a uno de aceptación.
+    live: Vec<bool>,
+    // Prefijos pendientes de explorar junto al estado al que llevan.
+    frontier: std::collections::VecDeque<(usize, String)>,
+}
+
+// Palabras que cambiaron de clasificación entre dos versiones de un autómata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageDiff {
+    // Palabras que la versión nueva acepta y la anterior rechazaba.
+    pub newly_accepted: Vec<String>,
+    // Palabras que la versión nueva rechaza y la anterior aceptaba.
+    pub newly_rejected: Vec<String>,
+}
+
+// Registro de la ejecución de un autómata sobre una palabra.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunTrace<S = char> {
+    // Estados visitados, empezando por el estado inicial.
+    pub states: Vec<String>,
+    // Símbolo consumido en cada paso; `symbols[i]` lleva de `states[i]` a `states[i + 1]`.
+    pub symbols: Vec<S>,
+    // Símbolo para el que no había transición, si la ejecución se detuvo antes de tiempo.
+    pub halted_on: Option<S>,
+    // Resultado de la ejecución.
+    pub result: RunResult<S>,
+}
+
+// Resultado de ejecutar el autómata sobre una palabra.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunResult<S = char> {
+    // La palabra fue aceptada.
+    Accepted,
+    // La palabra terminó en un estado de rechazo o le faltó una transición.
+    Rejected,
+    // La palabra contiene un símbolo que no pertenece al alfabeto.
+    InvalidSymbol(S),
+}
+
+// Motivo por el que una palabra fue aceptada o rechazada, pensado para mensajes de error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunExplanation {
+    // La palabra fue aceptada.
+    Accepted,
+    // Se consumió toda la palabra pero el último estado no es de aceptación.
+    RejectedAtEnd {
+        final_state: String,
+    },
+    // No hay transición desde `state` con el símbolo que está en la posición `position` (en bytes).
+    NoTransition {
+        position: usize,
+        state: String,
+        symbol: char,
+    },
+}
+
+impl<S: Symbol> RunTrace<S> {
+    // Indica si la palabra fue aceptada
+    pub fn accepted(&self) -> bool {
+        self.result == RunResult::Accepted
+    }
+
+    // Indica si la ejecución se detuvo por una transición faltante
+    pub fn halted(&self) -> bool {
+        self.halted_on.is_some()
+    }
+
+    // Retorna el último estado alcanzado
+    pub fn final_state(&self) -> &str {
+        self.states.last().map(|state| state.as_str()).unwrap_or("")
+    }
+
+    // Explica por qué se rechazó la palabra; retorna `None` si fue aceptada
+    pub fn rejection_message(&self) -> Option<String> {
+        match (&self.result, &self.halted_on) {
+            (RunResult::Accepted, _) => None,
+            (RunResult::InvalidSymbol(symbol), _) => {
+                Some(format!("El símbolo {} no pertenece al alfabeto", symbol))
+            }
+            (RunResult::Rejected, Some(symbol)) => {
+                Some(format!("No hay transición para el símbolo {}", symbol))
+            }
+            (RunResult::Rejected, None) => Some(format!(
+                "La palabra terminó en el estado: {}",
+                self.final_state()
+            )),
+        }
+    }
+}
+
+impl<S: Symbol> fmt::Display for RunTrace<S> {
+    // Muestra la derivación con la forma `q0 --0--> q0 --1--> q1`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.states[0])?;
+        for (symbol, state) in self.symbols.iter().zip(&self.states[1..]) {
+            write!(f, " --{}--> {}", symbol, state)?;
+        }
+        if let Some(symbol) = &self.halted_on {
+            write!(f, " --{}--> ✗", symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl Node {
+    // Crea un estado sin transiciones para un alfabeto de `symbols` símbolos
+    fn new(state: &str, is_accept: bool, symbols: usize) -> Node {
+        Node {
+            state: state.to_string(),
+            is_accept,
+            transitions: vec![None; symbols],
+        }
+    }
+}
+
+impl fmt::Debug for Node {
+    // Muestra el nombre, si es de aceptación y las transiciones definidas como
+    // `posición del símbolo en el alfabeto: índice del estado destino`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Node")
+            .field("state", &self.state)
+            .field("is_accept", &self.is_accept)
+            .field("transitions", &DebugTransitions(&self.transitions))
+            .finish()
+    }
+}
+
+// Envoltorio para mostrar la fila de transiciones de un `Node` como un mapa
+struct DebugTransitions<'a>(&'a [Option<usize>]);
+
+impl fmt::Debug for DebugTransitions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.0
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(symbol, next)| next.map(|next| (symbol, next))),
+            )
+            .finish()
+    }
+}
+
+impl<S: Symbol> fmt::Display for DFA<S> {
+    // Muestra la 5-tupla (definición formal del DFA) con la forma
+    // `A = <Q = {..}, Σ = {..}, q0, δ, F = {..}>`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "A = <Q = {}, Σ = {}, {}, δ, F = {}>",
+            self.format_states(),
+            self.format_alphabet(),
+            self.format_start_state(),
+            self.format_accept_states()
+        )
+    }
+}
+
+impl FromStr for DFA {
+    type Err = DfaParseError;
+
+    // Permite leer un autómata con `texto.parse::<DFA>()`
+    fn from_str(dfa_string: &str) -> Result<Self, Self::Err> {
+        DFA::from_string(dfa_string)
+    }
+}
+
+impl DFA {
+    /**
+     Lee un autómata desde su descripción textual: las líneas `alphabet={...}`, `state={...}`,
+     `start_state=...` y `F={...}` (o `F=*` para aceptar en todos los estados), seguidas de una
+     transición `(estado, símbolo)->destino` por línea. Las líneas vacías y lo que sigue a un `#`
+     separado por espacios son comentarios. Una transición comodín `(estado, *)->destino` vale para los
+     símbolos sin transición explícita desde ese estado: las líneas explícitas siempre tienen prioridad,
+     sin importar si aparecen antes o después del comodín.
+        # Arguments
+        * `dfa_string` - La descripción del autómata.
+        # Returns
+        Retorna un `DfaParseError` si alguna línea está mal formada o hace referencia a estados o
+        símbolos inexistentes.
+    */
+    pub fn from_string(dfa_string: &str) -> Result<Self, DfaParseError> {
+        DFA::from_string_with(dfa_string, read_char_alphabet, read_char_symbol)
+    }
+
+    /**
+     Lee un autómata desde una tabla de transiciones CSV con el formato que produce `to_csv`.
+     El estado inicial se marca con `->` y los de aceptación con `*` delante del nombre; las celdas vacías
+     indican que no hay transición. Se ignoran las líneas vacías.
+        # Arguments
+        * `csv` - La tabla; la primera fila lista los símbolos a partir de la segunda columna.
+        # Returns
+        Retorna un `DfaError` si falta el estado inicial, hay filas repetidas o mal formadas, o una celda
+        nombra un estado que no tiene fila.
+    */
+    pub fn from_csv(csv: &str) -> Result<DFA, DfaError> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (header_index, header) = lines.next().ok_or(DfaError::MissingStartState)?;
+        let malformed = |index: usize, line: &str| DfaError::MalformedCsv {
+            line: index + 1,
+            text: line.to_string(),
+        };
+
+        let mut builder = DfaBuilder::new();
+        let mut symbols = Vec::new();
+        for field in read_csv_fields(header).iter().skip(1) {
+            let symbol = read_char_symbol(field).ok_or_else(|| malformed(header_index, header))?;
+            if symbols.contains(&symbol) {
+                return Err(malformed(header_index, header));
+            }
+            builder.add_symbol(symbol);
+            symbols.push(symbol);
+        }
+
+        let mut has_start = false;
+        for (index, line) in lines {
+            let fields = read_csv_fields(line);
+            if fields.len() != symbols.len() + 1 {
+                return Err(malformed(index, line));
+            }
+
+            // Quitar las marcas `->` y `*`, en cualquier orden
+            let mut name = fields[0].as_str();
+            let (mut is_start, mut is_accept) = (false, false);
+            loop {
+                if let Some(rest) = name.strip_prefix("->") {
+                    is_start = true;
+                    name = rest;
+                } else if let Some(rest) = name.strip_prefix('*') {
+                    is_accept = true;
+                    name = rest;
+                } else {
+                    break;
+                }
+            }
+            let name = name.trim();
+
+            if is_start {
+                if has_start {
+                    return Err(malformed(index, line));
+                }
+                has_start = true;
+                builder.set_start(name);
+            }
+            builder.add_state(name, is_accept);
+            for (symbol, target) in symbols.iter().zip(&fields[1..]) {
+                if !target.is_empty() {
+                    builder.add_transition(name, *symbol, target);
+                }
+            }
+        }
+
+        builder.build()
+    }
+
+    /**
+     Lee un autómata guardado por JFLAP (archivo `.jff`).
+     Cada `<state>` se nombra con su atributo `name`, o con su `id` si no lo tiene; `<initial/>` y
+     `<final/>` marcan el estado inicial y los de aceptación, y cada `<transition>` se lee de sus
+     elementos `<from>`, `<to>` y `<read>`, que hacen referencia a los `id` de los estados.
+        # Arguments
+        * `xml` - El contenido del archivo.
+        # Returns
+        Retorna un `DfaError` si el XML está mal formado, no es un autómata finito, tiene transiciones
+        épsilon (un `<read>` vacío), símbolos de más de un carácter o no es determinista.
+    */
+    pub fn from_jflap(xml: &str) -> Result<DFA, DfaError> {
+        let root = xml::parse(xml).map_err(DfaError::MalformedXml)?;
+        if let Some(kind) = root.child("type") {
+            if kind.text != "fa" {
+                return Err(DfaError::MalformedXml(format!(
+                    "se esperaba un autómata finito (fa) pero el tipo es \"{}\"",
+                    kind.text
+                )));
+            }
+        }
+        // JFLAP 6 agrupa los estados y transiciones dentro de `<automaton>`; otras versiones no
+        let automaton = root.child("automaton").unwrap_or(&root);
+
+        let mut builder = DfaBuilder::new();
+        let mut names: HashMap<String, String> = HashMap::new();
+        let mut has_start = false;
+        for state in automaton.children_named("state") {
+            let id = state
+                .attribute("id")
+                .ok_or_else(|| DfaError::MalformedXml("un <state> no tiene id".to_string()))?;
+            let name = state.attribute("name").unwrap_or(id);
+            if names.insert(id.to_string(), name.to_string()).is_some() {
+                return Err(DfaError::DuplicateState(id.to_string()));
+            }
+            builder.add_state(name, state.child("final").is_some());
+            if state.child("initial").is_some() {
+                if has_start {
+                    return Err(DfaError::MalformedXml(
+                        "hay más de un estado inicial".to_string(),
+                    ));
+                }
+                has_start = true;
+                builder.set_start(name);
+            }
+        }
+
+        let field = |transition: &xml::Element, tag: &str| {
+            transition
+                .child(tag)
+                .map(|element| element.text.clone())
+                .ok_or_else(|| {
+                    DfaError::MalformedXml(format!("una <transition> no tiene <{}>", tag))
+                })
+        };
+        let name_of = |id: String| names.get(&id).cloned().ok_or(DfaError::UnknownState(id));
+        for transition in automaton.children_named("transition") {
+            let from = name_of(field(transition, "from")?)?;
+            let to = name_of(field(transition, "to")?)?;
+            let read = transition
+                .child("read")
+                .map(|read| read.text.as_str())
+                .unwrap_or("");
+            if read.is_empty() {
+                return Err(DfaError::EpsilonTransition { from, to });
+            }
+            let symbol = read_char_symbol(read).ok_or_else(|| {
+                DfaError::MalformedXml(format!("el símbolo \"{}\" tiene más de un carácter", read))
+            })?;
+            builder.add_transition(&from, symbol, &to);
+        }
+
+        builder.build()
+    }
+
+    /**
+     Compila una expresión regular en un autómata mínimo.
+     La expresión se convierte en un NFA con transiciones épsilon (construcción de Thompson), luego en
+     un DFA mediante la construcción de subconjuntos y por último se minimiza.
+        # Arguments
+        * `pattern` - La expresión. Admite concatenación, `|`, `*`, `+`, `?`, paréntesis y `\` para escapar operadores.
+        # Returns
+        Retorna un `DFA` cuyo alfabeto son los símbolos que aparecen en la expresión, o un `RegexError`
+        si la expresión está mal formada.
+    */
+    pub fn from_regex(pattern: &str) -> Result<DFA, RegexError> {
+        Ok(NFA::from_regex(pattern)?.to_dfa().minimize())
+    }
+
+    /**
+     Convierte el autómata en una expresión regular equivalente por eliminación de estados.
+     Se agregan un estado inicial y uno de aceptación nuevos y se eliminan los estados originales uno a
+     uno, combinando las etiquetas de las aristas con unión, concatenación y estrella.
+        # Returns
+        Retorna una expresión que `from_regex` puede leer; no necesariamente es la más corta. Si el
+        lenguaje es vacío retorna `∅`.
+    */
+    pub fn to_regex(&self) -> String {
+        let (start, accept) = (self.states.len(), self.states.len() + 1);
+
+        // edges[(p, q)] = expresión que lleva de p a q
+        let mut edges: HashMap<(usize, usize), Regex> = HashMap::new();
+        let add_edge = |edges: &mut HashMap<(usize, usize), Regex>, from, to, regex| {
+            let current = edges.remove(&(from, to)).unwrap_or(Regex::Nothing);
+            edges.insert((from, to), Regex::union(current, regex));
+        };
+
+        add_edge(&mut edges, start, self.start_state, Regex::Empty);
+        for (i, state) in self.states.iter().enumerate() {
+            if state.is_accept {
+                add_edge(&mut edges, i, accept, Regex::Empty);
+            }
+            for (symbol, next) in self.alphabet.iter().zip(&state.transitions) {
+                if let Some(next) = next {
+                    add_edge(&mut edges, i, *next, Regex::Symbol(*symbol));
+                }
+            }
+        }
+
+        // Eliminar los estados originales en el orden en que fueron declarados
+        for removed in 0..self.states.len() {
+            let self_loop =
+                Regex::star(edges.remove(&(removed, removed)).unwrap_or(Regex::Nothing));
+            let mut incoming: Vec<(usize, Regex)> = edges
+                .iter()
+                .filter(|((_, to), _)| *to == removed)
+                .map(|((from, _), regex)| (*from, regex.clone()))
+                .collect();
+            let mut outgoing: Vec<(usize, Regex)> = edges
+                .iter()
+                .filter(|((from, _), _)| *from == removed)
+                .map(|((_, to), regex)| (*to, regex.clone()))
+                .collect();
+            // Ordenar para que la expresión resultante no dependa del orden del `HashMap`
+            incoming.sort_by_key(|(from, _)| *from);
+            outgoing.sort_by_key(|(to, _)| *to);
+            edges.retain(|(from, to), _| *from != removed && *to != removed);
+
+            for (from, entering) in &incoming {
+                for (to, leaving) in &outgoing {
+                    let path = Regex::concat(
+                        entering.clone(),
+                        Regex::concat(self_loop.clone(), leaving.clone()),
+                    );
+                    add_edge(&mut edges, *from, *to, path);
+                }
+            }
+        }
+
+        edges
+            .remove(&(start, accept))
+            .unwrap_or(Regex::Nothing)
+            .to_string()
+    }
+
+    /**
+     Valida si la palabra ingresada es aceptada por el autómata.
+        # Arguments
+        * `input` - La palabra a analizar.
+        # Returns
+        Retorna un `bool` que puede determinar si la palabra es aceptada o no por el autómata. No imprime
+        nada; para saber por qué se rechazó una palabra se puede usar `run_trace`.
+    */
+    pub fn run(&self, input: &str) -> bool {
+        self.accepts(input.chars())
+    }
+
+    /**
+     Ejecuta el autómata sobre la palabra registrando cada paso.
+        # Arguments
+        * `input` - La palabra a analizar.
+        # Returns
+        Retorna un `RunTrace` con los estados visitados, los símbolos consumidos y el resultado.
+    */
+    pub fn run_trace(&self, input: &str) -> RunTrace {
+        let symbols: Vec<char> = input.chars().collect();
+        self.run_symbols_trace(&symbols)
+    }
+
+    /**
+     Explica el resultado de ejecutar el autómata sobre la palabra, indicando dónde y por qué fue rechazada.
+        # Arguments
+        * `input` - La palabra a analizar.
+        # Returns
+        Retorna un `RunExplanation` con el estado final o con la posición, el estado y el símbolo en el
+        que faltó una transición. Un símbolo fuera del alfabeto también se informa como `NoTransition`.
+    */
+    pub fn explain(&self, input: &str) -> RunExplanation {
+        let trace = self.run_trace(input);
+        match trace.halted_on {
+            Some(symbol) => RunExplanation::NoTransition {
+                position: input
+                    .char_indices()
+                    .nth(trace.symbols.len())
+                    .map_or(input.len(), |(position, _)| position),
+                state: trace.final_state().to_string(),
+                symbol,
+            },
+            None if trace.accepted() => RunExplanation::Accepted,
+            None => RunExplanation::RejectedAtEnd {
+                final_state: trace.final_state().to_string(),
+            },
+        }
+    }
+
+    /**
+     Ejecuta el autómata leyendo la palabra de un flujo, sin cargarla completa en memoria.
+     Los bytes se decodifican como UTF-8 a medida que llegan. La lectura se detiene en cuanto falta una
+     transición, sin consumir el resto del flujo.
+        # Arguments
+        * `reader` - La fuente de la palabra.
+        # Returns
+        Retorna si la palabra es aceptada, o un error de `io` si la lectura falla o el contenido no es UTF-8 válido.
+    */
+    pub fn run_reader<R: Read>(&self, reader: R) -> io::Result<bool> {
+        let mut reader = BufReader::new(reader);
+        let mut current_state = self.start_state;
+        let mut pending: Vec<u8> = Vec::with_capacity(4);
+
+        while let Some(&byte) = reader.fill_buf()?.first() {
+            reader.consume(1);
+            pending.push(byte);
+
+            let symbol = match std::str::from_utf8(&pending) {
+                Ok(text) => text.chars().next().unwrap(),
+                // Faltan bytes para completar el carácter
+                Err(error) if error.error_len().is_none() => continue,
+                Err(error) => return Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+            };
+            pending.clear();
+
+            match self.next_state(current_state, &symbol) {
+                Some(next) => current_state = next,
+                None => return Ok(false),
+            }
+        }
+
+        if !pending.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "el flujo termina a mitad de un carácter UTF-8",
+            ));
+        }
+
+        Ok(self.states[current_state].is_accept)
+    }
+
+    /**
+     Aplana el autómata en una tabla de transiciones para ejecuciones repetidas.
+        # Returns
+        Retorna un `CompiledDfa` que acepta exactamente las mismas palabras.
+    */
+    pub fn compile(&self) -> CompiledDfa {
+        let symbols = self.alphabet.len();
+        let mut table = vec![-1; self.states.len() * symbols];
+        let mut accepting = vec![0u64; self.states.len().div_ceil(64)];
+
+        for (i, state) in self.states.iter().enumerate() {
+            for (symbol, next) in state.transitions.iter().enumerate() {
+                if let Some(next) = next {
+                    table[i * symbols + symbol] = *next as i32;
+                }
+            }
+            if state.is_accept {
+                accepting[i / 64] |= 1 << (i % 64);
+            }
+        }
+
+        let mut ascii_index = [-1; 128];
+        let mut other_symbols = Vec::new();
+        for (index, &symbol) in self.alphabet.iter().enumerate() {
+            if symbol.is_ascii() {
+                ascii_index[symbol as usize] = index as i32;
+            } else {
+                other_symbols.push((symbol, index));
+            }
+        }
+
+        CompiledDfa {
+            table,
+            symbols,
+            ascii_index,
+            other_symbols,
+            accepting,
+            start_state: self.start_state as i32,
+        }
+    }
+
+    /**
+     Ejecuta el autómata distinguiendo los símbolos ajenos al alfabeto de las transiciones faltantes.
+        # Arguments
+        * `input` - La palabra a analizar.
+        # Returns
+        Retorna un `RunResult` con el resultado de la ejecución.
+    */
+    pub fn run_result(&self, input: &str) -> RunResult {
+        self.run_trace(input).result
+    }
+
+    /**
+     Valida la palabra sin distinguir mayúsculas de minúsculas: cada carácter se convierte a minúscula
+     antes de buscar su transición, por lo que el alfabeto debe estar escrito en minúsculas.
+     La conversión usa las reglas de Unicode sin tener en cuenta el idioma. Los caracteres cuya minúscula
+     tiene más de un carácter, como `İ` (que pasa a `i` seguida de un punto combinante), se buscan tal
+     cual. Tampoco se aplica el plegado completo de mayúsculas: `ß` no equivale a `ss`.
+        # Arguments
+        * `input` - La palabra a analizar.
+        # Returns
+        Retorna `true` si la palabra, convertida a minúsculas, es aceptada.
+    */
+    pub fn run_case_insensitive(&self, input: &str) -> bool {
+        self.accepts(input.chars().map(|symbol| {
+            let mut lowercase = symbol.to_lowercase();
+            match (lowercase.next(), lowercase.next()) {
+                (Some(lower), None) => lower,
+                _ => symbol,
+            }
+        }))
+    }
+
+    /**
+     Busca el prefijo aceptado más largo de la entrada, como lo haría un analizador léxico que toma
+     la coincidencia más larga. El recorrido se detiene en el primer símbolo sin transición.
+        # Arguments
+        * `input` - El texto a analizar.
+        # Returns
+        Retorna el prefijo y su longitud en bytes, o `None` si ningún prefijo (ni la palabra vacía)
+        es aceptado.
+    */
+    pub fn longest_accepted_prefix(&self, input: &str) -> Option<(String, usize)> {
+        self.accepting_positions(input)
+            .last()
+            .map(|&length| (input[..length].to_string(), length))
+    }
+
+    /**
+     Recorre la entrada una sola vez y registra cada posición en la que el autómata está en un estado de
+     aceptación, es decir, cada punto en que el prefijo leído es aceptado. El recorrido se detiene en el
+     primer símbolo sin transición.
+        # Arguments
+        * `input` - El texto a analizar.
+        # Returns
+        Retorna las posiciones en bytes en orden creciente; incluye el `0` si la palabra vacía es aceptada.
+    */
+    pub fn accepting_positions(&self, input: &str) -> Vec<usize> {
+        let mut state = self.start_state;
+        let mut positions = Vec::new();
+        if self.states[state].is_accept {
+            positions.push(0);
+        }
+
+        for (position, symbol) in input.char_indices() {
+            match self.next_state(state, &symbol) {
+                Some(next) => state = next,
+                None => break,
+            }
+            if self.states[state].is_accept {
+                positions.push(position + symbol.len_utf8());
+            }
+        }
+
+        positions
+    }
+
+    /**
+     Construye el autómata del cociente (derivada de Brzozowski) respecto de un prefijo: el lenguaje de
+     los sufijos que, después del prefijo, forman una palabra aceptada. El resultado tiene la misma
+     estructura que el original; solo cambia el estado inicial.
+        # Arguments
+        * `prefix` - El prefijo que se lee desde el estado inicial.
+        # Returns
+        Retorna el `DFA` que empieza en el estado alcanzado tras el prefijo, o `None` si al prefijo le
+        falta alguna transición.
+    */
+    pub fn residual(&self, prefix: &str) -> Option<DFA> {
+        match self.walk(prefix.chars()) {
+            (state, None) => {
+                let mut dfa = self.clone();
+                dfa.start_state = state;
+                Some(dfa)
+            }
+            (_, Some(_)) => None,
+        }
+    }
+
+    /**
+     Decide si algún prefijo de la entrada, incluida la palabra vacía, es aceptado. Es más barato que
+     `longest_accepted_prefix` porque termina en cuanto el autómata pasa por un estado de aceptación.
+        # Arguments
+        * `input` - El texto a analizar.
+        # Returns
+        Retorna `true` si el autómata está en un estado de aceptación en algún momento del recorrido.
+    */
+    pub fn accepts_any_prefix(&self, input: &str) -> bool {
+        let mut state = self.start_state;
+        if self.states[state].is_accept {
+            return true;
+        }
+        for symbol in input.chars() {
+            match self.next_state(state, &symbol) {
+                Some(next) if self.states[next].is_accept => return true,
+                Some(next) => state = next,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /**
+     Valida varias palabras sin imprimir mensajes por cada una.
+        # Arguments
+        * `words` - Las palabras a analizar.
+        # Returns
+        Retorna un `Vec<bool>` con el resultado de cada palabra, en el mismo orden.
+    */
+    pub fn run_many<'a>(&self, words: impl IntoIterator<Item = &'a str>) -> Vec<bool> {
+        words
+            .into_iter()
+            .map(|word| self.accepts(word.chars()))
+            .collect()
+    }
+
+    /**
+     Enumera las palabras aceptadas de longitud menor o igual a `max_len`.
+        # Arguments
+        * `max_len` - La longitud máxima de las palabras.
+        # Returns
+        Retorna un `Vec<String>` ordenado por longitud y luego lexicográficamente según el alfabeto.
+    */
+    pub fn accepted_words(&self, max_len: usize) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut level = vec![(self.start_state, String::new())];
+
+        for length in 0..=max_len {
+            let mut next_level = Vec::new();
+            for (state, prefix) in level {
+                if self.states[state].is_accept {
+                    words.push(prefix.clone());
+                }
+                if length == max_len {
+                    continue;
+                }
+                for (symbol, next) in self.alphabet.iter().zip(&self.states[state].transitions) {
+                    if let Some(next) = next {
+                        let mut word = prefix.clone();
+                        word.push(*symbol);
+                        next_level.push((*next, word));
+                    }
+                }
+            }
+            level = next_level;
+        }
+
+        words
+    }
+
+    /**
+     Enumera como `accepted_words` las palabras aceptadas de longitud menor o igual a `max_len`, pero se
+     detiene en cuanto reúne `max_results` palabras. El recorrido por niveles nunca pasa de `max_len`
+     y descarta los prefijos que ya no pueden llegar a un estado de aceptación.
+        # Arguments
+        * `max_len` - La longitud máxima de las palabras.
+        * `max_results` - La cantidad máxima de palabras a retornar.
+        # Returns
+        Retorna un `Vec<String>` con las primeras `max_results` palabras, ordenadas por longitud y luego
+        lexicográficamente según el alfabeto.
+    */
+    pub fn accepted_words_limited(&self, max_len: usize, max_results: usize) -> Vec<String> {
+        let mut words = Vec::new();
+        let live = self.coreachable_mask();
+        let mut level = Vec::new();
+        if live[self.start_state] {
+            level.push((self.start_state, String::new()));
+        }
+
+        for length in 0..=max_len {
+            let mut next_level = Vec::new();
+            for (state, prefix) in level {
+                if words.len() == max_results {
+                    return words;
+                }
+                if self.states[state].is_accept {
+                    words.push(prefix.clone());
+                }
+                if length == max_len {
+                    continue;
+                }
+                for (symbol, next) in self.alphabet.iter().zip(&self.states[state].transitions) {
+                    match next {
+                        Some(next) if live[*next] => {
+                            let mut word = prefix.clone();
+                            word.push(*symbol);
+                            next_level.push((*next, word));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            level = next_level;
+        }
+
+        words.truncate(max_results);
+        words
+    }
+
+    /**
+     Enumera de forma perezosa las palabras aceptadas, ordenadas por longitud y luego
+     lexicográficamente según el alfabeto. Si el lenguaje es infinito el iterador no termina, por lo
+     que conviene limitarlo con `take`.
+        # Returns
+        Retorna un `LanguageIter` que produce las palabras a medida que se piden.
+    */
+    pub fn iter_language(&self) -> LanguageIter<'_> {
+        let live = self.coreachable_mask();
+        let mut frontier = std::collections::VecDeque::new();
+        if live[self.start_state] {
+            frontier.push_back((self.start_state, String::new()));
+        }
+        LanguageIter {
+            dfa: self,
+            live,
+            frontier,
+        }
+    }
+
+    /**
+     Genera una palabra aceptada al azar mediante un recorrido aleatorio desde el estado inicial.
+     En cada paso solo se eligen transiciones desde las que todavía se puede aceptar sin superar
+     `max_len`; en un estado de aceptación, detenerse es una opción más junto a esas transiciones.
+        # Arguments
+        * `rng` - El generador de números aleatorios.
+        * `max_len` - La longitud máxima de la palabra.
+        # Returns
+        Retorna la palabra generada, o `None` si no hay palabras aceptadas de longitud a lo sumo `max_len`.
+    */
+    #[cfg(feature = "rand")]
+    pub fn random_accepted_word(&self, rng: &mut impl rand::Rng, max_len: usize) -> Option<String> {
+        let distance = self.distances_to_accept();
+        if distance[self.start_state] > max_len {
+            return None;
+        }
+
+        let mut state = self.start_state;
+        let mut word = String::new();
+        for remaining in (0..=max_len).rev() {
+            let choices: Vec<(char, usize)> = self
+                .alphabet
+                .iter()
+                .zip(&self.states[state].transitions)
+                .filter_map(|(&symbol, next)| next.map(|next| (symbol, next)))
+                .filter(|&(_, next)| distance[next] < remaining)
+                .collect();
+
+            // `None` representa detenerse, posible solo en un estado de aceptación
+            let stop = usize::from(self.states[state].is_accept);
+            let choice = rng.gen_range(0..choices.len() + stop);
+            match choices.get(choice) {
+                Some(&(symbol, next)) => {
+                    word.push(symbol);
+                    state = next;
+                }
+                None => return Some(word),
+            }
+        }
+
+        // Con el presupuesto agotado el recorrido siempre termina en un estado de aceptación
+        Some(word)
+    }
+
+    /**
+     Busca la palabra aceptada más corta mediante un recorrido en anchura desde el estado inicial.
+     Los empates se resuelven según el orden de los símbolos del alfabeto.
+        # Returns
+        Retorna la palabra encontrada, o `None` si el lenguaje es vacío.
+    */
+    pub fn shortest_accepted_word(&self) -> Option<String> {
+        let mut visited = vec![false; self.states.len()];
+        let mut queue = std::collections::VecDeque::new();
+        visited[self.start_state] = true;
+        queue.push_back((self.start_state, String::new()));
+
+        while let Some((state, word)) = queue.pop_front() {
+            if self.states[state].is_accept {
+                return Some(word);
+            }
+            for (symbol, next) in self.alphabet.iter().zip(&self.states[state].transitions) {
+                if let Some(next) = *next {
+                    if !visited[next] {
+                        visited[next] = true;
+                        let mut next_word = word.clone();
+                        next_word.push(*symbol);
+                        queue.push_back((next, next_word));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /**
+     Minimiza el autómata y nombra cada estado con su palabra de acceso: la menor palabra, en orden
+     shortlex, que lleva desde el estado inicial hasta él. El estado inicial se llama `ε` y los demás
+     quedan como `0`, `1`, `01`, etc. El autómata se completa con un estado trampa antes de minimizar,
+     así que dos autómatas que reconocen el mismo lenguaje producen los mismos nombres y las mismas
+     transiciones aunque uno sea parcial y el otro completo.
+        # Returns
+        Retorna un `DFA` mínimo con los estados renombrados y ordenados según su palabra de acceso.
+    */
+    pub fn relabel_by_language_residual(&self) -> DFA {
+        // Completar primero hace que `minimize` conserve siempre la clase trampa, si existe.
+        // Tras canonicalizar, los estados quedan en el orden del recorrido en anchura
+        let mut dfa = self.complete_with_trap().minimize().canonicalize();
+        let mut access: Vec<Option<String>> = vec![None; dfa.states.len()];
+        let mut queue = std::collections::VecDeque::new();
+        access[dfa.start_state] = Some(String::new());
+        queue.push_back(dfa.start_state);
+
+        // Recorrer los símbolos en orden encuentra primero la palabra menor en orden shortlex
+        while let Some(state) = queue.pop_front() {
+            let word = access[state].clone().unwrap_or_default();
+            for (symbol, next) in dfa.alphabet.iter().zip(&dfa.states[state].transitions) {
+                if let Some(next) = *next {
+                    if access[next].is_none() {
+                        let mut next_word = word.clone();
+                        next_word.push(*symbol);
+                        access[next] = Some(next_word);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        for (state, word) in dfa.states.iter_mut().zip(access) {
+            if let Some(word) = word {
+                state.state = if word.is_empty() {
+                    "ε".to_string()
+                } else {
+                    word
+                };
+            }
+        }
+        dfa
+    }
+
+    /**
+     Busca la palabra más corta en la que los dos autómatas no coinciden, es decir, la palabra aceptada
+     más corta de su diferencia simétrica.
+        # Arguments
+        * `other` - El otro autómata; su alfabeto puede ser distinto.
+        # Returns
+        Retorna una palabra que uno de los autómatas acepta y el otro rechaza, o `None` si son equivalentes.
+    */
+    pub fn counterexample(&self, other: &DFA) -> Option<String> {
+        self.symmetric_difference(other).shortest_accepted_word()
+    }
+
+    /**
+     Compara este autómata con una versión anterior y lista las palabras que cambiaron de clasificación,
+     usando las dos diferencias y `accepted_words`.
+        # Arguments
+        * `other` - La versión anterior del autómata; su alfabeto puede ser distinto.
+        * `max_len` - La longitud máxima de las palabras a listar.
+        # Returns
+        Retorna un `LanguageDiff` con las palabras aceptadas solo por este autómata y las aceptadas solo
+        por `other`, en orden por longitud y luego lexicográfico.
+    */
+    pub fn language_diff(&self, other: &DFA, max_len: usize) -> LanguageDiff {
+        LanguageDiff {
+            newly_accepted: self.difference(other).accepted_words(max_len),
+            newly_rejected: other.difference(self).accepted_words(max_len),
+        }
+    }
+}
+
+impl DFA<String> {
+    /**
+     Lee un autómata cuyo alfabeto está formado por tokens de uno o más caracteres, como `if` o `while`.
+     Los tokens se separan por comas y pueden escribirse entre comillas, por ejemplo `"a,b"`.
+        # Arguments
+        * `dfa_string` - La descripción del autómata en el mismo formato que lee `from_string`.
+        # Returns
+        Retorna un `DfaParseError` si alguna transición está mal formada o hace referencia a estados o
+        tokens inexistentes.
+    */
+    pub fn from_token_string(dfa_string: &str) -> Result<Self, DfaParseError<String>> {
+        DFA::from_string_with(dfa_string, read_token_alphabet, read_token_symbol)
+    }
+
+    /**
+     Valida si la secuencia de tokens es aceptada por el autómata.
+        # Arguments
+        * `tokens` - Los tokens a analizar, en orden.
+        # Returns
+        Retorna un `bool` que puede determinar si la secuencia es aceptada o no por el autómata.
+    */
+    pub fn run_tokens(&self, tokens: &[&str]) -> bool {
+        let symbols: Vec<String> = tokens.iter().map(|token| token.to_string()).collect();
+        self.run_symbols(&symbols)
+    }
+}
+
+impl DFA<u8> {
+    /**
+     Lee un autómata cuyo alfabeto está formado por bytes, para reconocer datos binarios sin
+     decodificarlos como UTF-8. Cada byte se escribe como un carácter ASCII o con la forma `\xNN`,
+     por ejemplo `alphabet={\x00, \xFF, a}`.
+        # Arguments
+        * `dfa_string` - La descripción del autómata en el mismo formato que lee `from_string`.
+        # Returns
+        Retorna un `DfaParseError` si alguna transición está mal formada o hace referencia a estados o
+        bytes inexistentes.
+    */
+    pub fn from_byte_string(dfa_string: &str) -> Result<Self, DfaParseError<u8>> {
+        DFA::from_string_with(dfa_string, read_byte_alphabet, read_byte_symbol)
+    }
+
+    /**
+     Valida si la secuencia de bytes es aceptada por el autómata. La posición de cada byte en el
+     alfabeto se busca en una tabla de 256 entradas en lugar de una búsqueda binaria.
+        # Arguments
+        * `input` - Los bytes a analizar.
+        # Returns
+        Retorna un `bool` que puede determinar si la secuencia es aceptada o no por el autómata.
+    */
+    pub fn run_bytes(&self, input: &[u8]) -> bool {
+        let mut symbol_of = [None; 256];
+        for (index, &byte) in self.alphabet.iter().enumerate() {
+            symbol_of[byte as usize] = Some(index);
+        }
+
+        let mut state = self.start_state;
+        for &byte in input {
+            match symbol_of[byte as usize].and_then(|symbol| self.states[state].transitions[symbol])
+            {
+                Some(next) => state = next,
+                None => return false,
+            }
+        }
+        self.states[state].is_accept
+    }
+}
+
+impl<S: Symbol> DFA<S> {
+    // Crea un autómata sin estados sobre el alfabeto dado, que se ordena y se deja sin repetidos
+    fn with_alphabet(alphabet: impl IntoIterator<Item = S>) -> DFA<S> {
+        let mut alphabet: Vec<S> = alphabet.into_iter().collect();
+        alphabet.sort();
+        alphabet.dedup();
+
+        DFA {
+            states: Vec::new(),
+            alphabet,
+            start_state: 0,
+        }
+    }
+
+    // Agrega un estado sin transiciones y retorna su posición
+    fn push_state(&mut self, name: &str, is_accept: bool) -> usize {
+        self.states
+            .push(Node::new(name, is_accept, self.alphabet.len()));
+        self.states.len() - 1
+    }
+
+    // Retorna la posición de un símbolo dentro del alfabeto
+    fn symbol_index(&self, symbol: &S) -> Option<usize> {
+        self.alphabet.binary_search(symbol).ok()
+    }
+
+    // Retorna la posición de un estado a partir de su nombre
+    fn state_index(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|state| state.state == name)
+    }
+
+    // Retorna el estado al que se llega desde `state` leyendo `symbol`
+    fn next_state(&self, state: usize, symbol: &S) -> Option<usize> {
+        self.states[state].transitions[self.symbol_index(symbol)?]
+    }
+
+    // Retorna el nombre del estado inicial
+    fn start_name(&self) -> &str {
+        &self.states[self.start_state].state
+    }
+
+    /**
+     Lee la descripción textual de un autómata delegando la lectura de los símbolos.
+        # Arguments
+        * `dfa_string` - La descripción del autómata.
+        * `read_alphabet` - Convierte el contenido de la línea `alphabet=` en la lista de símbolos.
+        * `read_symbol` - Convierte el símbolo de una transición; retorna `None` si está mal formado.
+        # Returns
+        Retorna un `DfaParseError` si alguna transición está mal formada o hace referencia a estados o
+        símbolos inexistentes.
+    */
+    fn from_string_with(
+        dfa_string: &str,
+        read_alphabet: fn(&str) -> Vec<S>,
+        read_symbol: fn(&str) -> Option<S>,
+    ) -> Result<DFA<S>, DfaParseError<S>> {
+        let mut alphabet = Vec::new();
+        let mut state_names: Vec<String> = Vec::new();
+        let mut declared: HashSet<String> = HashSet::new();
+        // Los estados inicial y finales guardan su línea para reportar nombres no declarados
+        let mut start_name: Option<(usize, String)> = None;
+        let mut final_names: Vec<(usize, String)> = Vec::new();
+        // `F=*` indica que todos los estados son de aceptación
+        let mut all_final = false;
+        // Las transiciones se procesan al final para validar sus símbolos contra el alfabeto completo
+        let mut transition_lines: Vec<(usize, &str)> = Vec::new();
+
+        // Procesar cada línea del string
+        for (index, line) in dfa_string.lines().enumerate() {
+            let line = strip_trailing_comment(line).trim();
+
+            // Las líneas vacías y los comentarios (que empiezan con `#`) se ignoran
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // Procesar el alfabeto
+            if line.starts_with("alphabet=") {
+                let symbols = line
+                    .trim_start_matches("alphabet=")
+                    .trim()
+                    .trim_start_matches('{')
+                    .trim_end_matches('}');
+                alphabet.extend(read_alphabet(symbols));
+            }
+            // Procesar los estados
+            else if line.starts_with("state=") {
+                for name in read_state_names(line.trim_start_matches("state=")) {
+                    if !declared.insert(name.clone()) {
+                        return Err(DfaParseError::DuplicateState {
+                            line: index + 1,
+                            name,
+                        });
+                    }
+                    state_names.push(name);
+                }
+            }
+            // Procesar el estado inicial
+            else if line.starts_with("start_state=") {
+                let name = line.trim_start_matches("start_state=").trim();
+                start_name = Some((index + 1, unquote_state_name(name)));
+            }
+            // Procesar los estados finales; varias líneas `F=` se acumulan. `F=*` marca todos los
+            // estados y `F={}` ninguno
+            else if line.starts_with("F=") {
+                match line.trim_start_matches("F=").trim() {
+                    "*" => all_final = true,
+                    "" => {
+                        return Err(DfaParseError::MalformedAcceptStates {
+                            line: index + 1,
+                            text: line.to_string(),
+                        })
+                    }
+                    names => final_names.extend(
+                        read_state_names(names)
+                            .into_iter()
+                            .map(|name| (index + 1, name)),
+                    ),
+                }
+            }
+            // Procesar las transiciones
+            else if line.starts_with("(") {
+                transition_lines.push((index + 1, line));
+            } else {
+                return Err(DfaParseError::UnrecognizedLine {
+                    line: index + 1,
+                    text: line.to_string(),
+                });
+            }
+        }
+
+        let mut dfa = DFA::with_alphabet(alphabet);
+        for name in &state_names {
+            dfa.push_state(name, all_final);
+        }
+        // Un estado repetido en `F=` se considera una sola vez
+        let mut seen_finals: HashSet<String> = HashSet::new();
+        final_names.retain(|(_, name)| seen_finals.insert(name.clone()));
+        // Se reúnen todos los estados de aceptación desconocidos para informarlos juntos
+        let mut unknown_finals: Vec<(usize, String)> = Vec::new();
+        for (line, name) in final_names {
+            match dfa.state_index(&name) {
+                Some(index) => dfa.states[index].is_accept = true,
+                None => unknown_finals.push((line, name)),
+            }
+        }
+        if let Some(&(line, _)) = unknown_finals.first() {
+            return Err(DfaParseError::UnknownAcceptStates {
+                line,
+                names: unknown_finals.into_iter().map(|(_, name)| name).collect(),
+            });
+        }
+        dfa.start_state = match start_name {
+            Some((line, name)) => dfa
+                .state_index(&name)
+                .ok_or(DfaParseError::UnknownStartState { line, name })?,
+            None => return Err(DfaParseError::MissingStartState),
+        };
+
+        // Los comodines se aplican al final, a los símbolos sin transición explícita
+        let mut wildcards: Vec<(usize, usize)> = Vec::new();
+        for (line_number, line) in transition_lines {
+            match dfa.read_wildcard_line(line, line_number, read_symbol)? {
+                Some((from, _)) if wildcards.iter().any(|&(other, _)| other == from) => {
+                    return Err(DfaParseError::DuplicateWildcard {
+                        line: line_number,
+                        state: dfa.states[from].state.clone(),
+                    });
+                }
+                Some(wildcard) => wildcards.push(wildcard),
+                None => {
+                    dfa.add_transition_line(line, line_number, read_symbol)?;
+                }
+            }
+        }
+        for (from, to) in wildcards {
+            for next in &mut dfa.states[from].transitions {
+                next.get_or_insert(to);
+            }
+        }
+
+        Ok(dfa)
+    }
+
+    /**
+     Reconoce una transición comodín `(estado, *)->destino`, que vale para todos los símbolos del alfabeto
+     sin una transición explícita desde ese estado. Si `*` pertenece al alfabeto la línea es una
+     transición normal.
+        # Arguments
+        * `input` - La línea de transición.
+        * `line` - El número de la línea dentro de la descripción.
+        * `read_symbol` - Convierte el texto del símbolo, para saber si `*` es un símbolo del alfabeto.
+        # Returns
+        Retorna los estados de origen y destino si la línea es un comodín, `None` si no lo es, o un
+        `DfaParseError` si está mal formada o hace referencia a estados inexistentes.
+    */
+    fn read_wildcard_line(
+        &self,
+        input: &str,
+        line: usize,
+        read_symbol: fn(&str) -> Option<S>,
+    ) -> Result<Option<(usize, usize)>, DfaParseError<S>> {
+        let (from, symbol, to) = split_transition_line(input, line)?;
+        let is_symbol =
+            read_symbol(symbol).is_some_and(|symbol| self.symbol_index(&symbol).is_some());
+        if symbol != "*" || is_symbol {
+            return Ok(None);
+        }
+
+        let index = |name: &str| {
+            self.state_index(name)
+                .ok_or_else(|| DfaParseError::UnknownState {
+                    line,
+                    name: name.to_string(),
+                })
+        };
+        Ok(Some((index(&from)?, index(&to)?)))
+    }
+
+    /**
+     Interpreta una línea de transición con la forma `(estado, símbolo)->estado` y la agrega al autómata.
+        # Arguments
+        * `input` - La línea de transición.
+        * `line` - El número de la línea dentro de la descripción.
+        * `read_symbol` - Convierte el texto del símbolo; retorna `None` si está mal formado.
+        # Returns
+        Retorna el estado de origen y la posición del símbolo de la transición agregada, o un
+        `DfaParseError` si la línea está mal formada o hace referencia a estados o símbolos inexistentes.
+    */
+    fn add_transition_line(
+        &mut self,
+        input: &str,
+        line: usize,
+        read_symbol: fn(&str) -> Option<S>,
+    ) -> Result<(usize, usize), DfaParseError<S>> {
+        let (state_input, symbol_input, next_state_name) = split_transition_line(input, line)?;
+        let symbol = read_symbol(symbol_input).ok_or_else(|| DfaParseError::MalformedSymbol {
+            line,
+            text: symbol_input.to_string(),
+        })?;
+
+        let symbol_index = match self.symbol_index(&symbol) {
+            Some(symbol_index) => symbol_index,
+            None => return Err(DfaParseError::UnknownSymbol { line, symbol }),
+        };
+
+        // Buscar el estado actual
+        let current =
+            self.state_index(&state_input)
+                .ok_or_else(|| DfaParseError::UnknownState {
+                    line,
+                    name: state_input.clone(),
+                })?;
+
+        // Buscar el estado destino
+        let next = self
+            .state_index(&next_state_name)
+            .ok_or(DfaParseError::UnknownState {
+                line,
+                name: next_state_name,
+            })?;
+
+        // Un autómata determinista no puede tener dos transiciones para el mismo par (estado, símbolo)
+        if self.states[current].transitions[symbol_index].is_some() {
+            return Err(DfaParseError::DuplicateTransition {
+                line,
+                state: state_input,
+                symbol,
+            });
+        }
+
+        // Agregar la transición al estado destino
+        self.states[current].transitions[symbol_index] = Some(next);
+        Ok((current, symbol_index))
+    }
+
+    // Recorre el autómata desde el estado inicial; se detiene en el primer símbolo sin transición
+    fn walk<T: Borrow<S>>(&self, input: impl IntoIterator<Item = T>) -> (usize, Option<T>) {
+        let mut current_state = self.start_state;
+        for symbol in input {
+            match self.next_state(current_state, symbol.borrow()) {
+                Some(next) => current_state = next,
+                None => return (current_state, Some(symbol)),
+            }
+        }
+        (current_state, None)
+    }
+
+    // Valida la palabra sin imprimir mensajes
+    pub fn accepts<T: Borrow<S>>(&self, input: impl IntoIterator<Item = T>) -> bool {
+        match self.walk(input) {
+            (state, None) => self.states[state].is_accept,
+            (_, Some(_)) => false,
+        }
+    }
+
+    // Indica si la palabra vacía pertenece al lenguaje, es decir, si el estado inicial es de aceptación
+    pub fn accepts_empty(&self) -> bool {
+        self.states[self.start_state].is_accept
+    }
+
+    pub fn run_symbols(&self, input: &[S]) -> bool {
+        self.accepts(input)
+    }
+
+    /**
+     Ejecuta el autómata sobre la palabra registrando cada paso.
+        # Arguments
+        * `input` - Los símbolos de la palabra a analizar.
+        # Returns
+        Retorna un `RunTrace` con los estados visitados, los símbolos consumidos y el resultado.
+    */
+    pub fn run_symbols_trace(&self, input: &[S]) -> RunTrace<S> {
+        let mut current_state = self.start_state;
+        let mut trace = RunTrace {
+            states: vec![self.start_name().to_string()],
+            symbols: Vec::new(),
+            halted_on: None,
+            result: RunResult::Rejected,
+        };
+
+        for symbol in input {
+            match self.next_state(current_state, symbol) {
+                Some(next) => {
+                    trace.symbols.push(symbol.clone());
+                    trace.states.push(self.states[next].state.clone());
+                    current_state = next;
+                }
+                None => {
+                    trace.halted_on = Some(symbol.clone());
+                    if self.symbol_index(symbol).is_none() {
+                        trace.result = RunResult::InvalidSymbol(symbol.clone());
+                    }
+                    return trace;
+                }
+            }
+        }
+
+        if self.states[current_state].is_accept {
+            trace.result = RunResult::Accepted;
+        }
+        trace
+    }
+
+    /**
+     Reconstruye un autómata a partir de su representación plana.
+        # Arguments
+        * `data` - Los estados, el alfabeto, el estado inicial y las transiciones.
+        # Returns
+        Retorna un `DfaError` si hay estados duplicados, referencias a estados o símbolos inexistentes
+        o transiciones repetidas.
+    */
+    pub fn from_data(data: DfaData<S>) -> Result<DFA<S>, DfaError<S>> {
+        let mut dfa = DFA::with_alphabet(data.alphabet);
+        let mut by_name: HashMap<String, usize> = HashMap::new();
+
+        for state in data.states {
+            if by_name.contains_key(&state.name) {
+                return Err(DfaError::DuplicateState(state.name));
+            }
+            let index = dfa.push_state(&state.name, state.is_accept);
+            by_name.insert(state.name, index);
+        }
+
+        let find = |name: &str| {
+            by_name
+                .get(name)
+                .copied()
+                .ok_or_else(|| DfaError::UnknownState(name.to_string()))
+        };
+
+        for transition in data.transitions {
+            let symbol = match dfa.symbol_index(&transition.symbol) {
+                Some(symbol) => symbol,
+                None => return Err(DfaError::UnknownSymbol(transition.symbol)),
+            };
+            let from = find(&transition.from)?;
+            let to = find(&transition.to)?;
+            if dfa.states[from].transitions[symbol].is_some() {
+                return Err(DfaError::DuplicateTransition {
+                    state: transition.from,
+                    symbol: transition.symbol,
+                });
+            }
+            dfa.states[from].transitions[symbol] = Some(to);
+        }
+
+        dfa.start_state = find(&data.start_state)?;
+
+        Ok(dfa)
+    }
+
+    // Retorna la cantidad de estados
+    pub fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    // Retorna la cantidad de transiciones definidas entre todos los estados
+    pub fn transition_count(&self) -> usize {
+        self.states
+            .iter()
+            .map(|state| state.transitions.iter().flatten().count())
+            .sum()
+    }
+
+    // Retorna la cantidad de símbolos del alfabeto
+    pub fn alphabet_size(&self) -> usize {
+        self.alphabet.len()
+    }
+
+    // Retorna los nombres de los estados en el orden en que fueron declarados
+    pub fn states(&self) -> impl Iterator<Item = &str> {
+        self.states.iter().map(|state| state.state.as_str())
+    }
+
+    /**
+     Consulta si un estado es de aceptación.
+        # Arguments
+        * `name` - El nombre del estado.
+        # Returns
+        Retorna `None` si el estado no existe.
+    */
+    pub fn is_accepting_state(&self, name: &str) -> Option<bool> {
+        self.state_index(name)
+            .map(|state| self.states[state].is_accept)
+    }
+
+    /**
+     Consulta el destino de una transición.
+        # Arguments
+        * `from` - El nombre del estado de origen.
+        * `symbol` - El símbolo leído.
+        # Returns
+        Retorna el nombre del estado destino, o `None` si el estado no existe o la transición no está definida.
+    */
+    pub fn transition(&self, from: &str, symbol: S) -> Option<String> {
+        let next = self.next_state(self.state_index(from)?, &symbol)?;
+        Some(self.states[next].state.clone())
+    }
+
+    /**
+     Expone la función de transición δ como una clausura, para usar el autómata en algoritmos externos
+     sin acceder a su representación interna.
+        # Returns
+        Retorna una función equivalente a `transition`, que toma prestado el autómata.
+    */
+    pub fn as_transition_fn(&self) -> impl Fn(&str, S) -> Option<String> + '_ {
+        move |from, symbol| self.transition(from, symbol)
+    }
+
+    /**
+     Verifica si la función de transición es total.
+        # Returns
+        Retorna `true` si cada estado tiene una transición definida para cada símbolo del alfabeto.
+    */
+    pub fn is_complete(&self) -> bool {
+        self.states
+            .iter()
+            .all(|state| state.transitions.iter().all(|next| next.is_some()))
+    }
+
+    /**
+     Lista las transiciones que faltan para que la función de transición sea total.
+        # Returns
+        Retorna un `Vec<(String, S)>` con los pares (estado, símbolo) sin transición definida.
+    */
+    pub fn missing_transitions(&self) -> Vec<(String, S)> {
+        let mut missing = Vec::new();
+        for state in &self.states {
+            for (symbol, next) in self.alphabet.iter().zip(&state.transitions) {
+                if next.is_none() {
+                    missing.push((state.state.clone(), symbol.clone()));
+                }
+            }
+        }
+        missing
+    }
+
+    // Copia el autómata agregando símbolos al alfabeto; los símbolos nuevos no tienen transiciones
+    fn extend_alphabet(&self, symbols: impl IntoIterator<Item = S>) -> DFA<S> {
+        let mut dfa = DFA::with_alphabet(self.alphabet.iter().cloned().chain(symbols));
+        for state in &self.states {
+            let node = dfa.push_state(&state.state, state.is_accept);
+            for (symbol, next) in self.alphabet.iter().zip(&state.transitions) {
+                let position = dfa.symbol_index(symbol).unwrap();
+                dfa.states[node].transitions[position] = *next;
+            }
+        }
+        dfa.start_state = self.start_state;
+        dfa
+    }
+
+    /**
+     Completa el autómata agregando un estado trampa llamado `__trap__`.
+        # Returns
+        Retorna un `DFA` cuya función de transición es total.
+    */
+    pub fn complete_with_trap(&self) -> DFA<S> {
+        self.complete_with_trap_named("__trap__")
+    }
+
+    /**
+     Completa el autómata dirigiendo cada transición faltante a un estado trampa de no aceptación.
+     Si el autómata ya es completo no se agrega ningún estado.
+        # Arguments
+        * `trap_name` - El nombre del estado trampa. Si ya existe un estado con ese nombre se le agregan guiones bajos.
+        # Returns
+        Retorna un `DFA` cuya función de transición es total.
+    */
+    pub fn complete_with_trap_named(&self, trap_name: &str) -> DFA<S> {
+        let mut dfa = self.clone();
+
+        if dfa.is_complete() {
+            return dfa;
+        }
+
+        let mut name = trap_name.to_string();
+        while dfa.state_index(&name).is_some() {
+            name.push('_');
+        }
+
+        let trap = dfa.push_state(&name, false);
+        for state in &mut dfa.states {
+            for next in &mut state.transitions {
+                next.get_or_insert(trap);
+            }
+        }
+
+        dfa
+    }
+
+    /**
+     Renombra los estados como `q0, q1, ...` según un recorrido en anchura desde el estado inicial, que
+     pasa a llamarse `q0`. Los estados inalcanzables se numeran al final, en el orden en que fueron declarados.
+     Dos autómatas iguales salvo por los nombres de sus estados producen el mismo resultado.
+        # Returns
+        Retorna un `DFA` con los estados renombrados y ordenados según su nuevo número.
+    */
+    pub fn canonicalize(&self) -> DFA<S> {
+        let mut order = self.reachable_indices();
+        let mut position = vec![usize::MAX; self.states.len()];
+        for (i, &state) in order.iter().enumerate() {
+            position[state] = i;
+        }
+        for (state, position) in position.iter_mut().enumerate() {
+            if *position == usize::MAX {
+                *position = order.len();
+                order.push(state);
+            }
+        }
+
+        let mut dfa = DFA::with_alphabet(self.alphabet.clone());
+        for (i, &state) in order.iter().enumerate() {
+            dfa.push_state(&format!("q{}", i), self.states[state].is_accept);
+            dfa.states[i].transitions = self.states[state]
+                .transitions
+                .iter()
+                .map(|next| next.map(|next| position[next]))
+                .collect();
+        }
+        dfa.start_state = position[self.start_state];
+        dfa
+    }
+
+    /**
+     Calcula una huella del lenguaje reconocido: se completa el autómata, se minimiza, se canonicaliza y
+     se calcula el hash del alfabeto, el estado inicial y la estructura de transiciones resultante. Como el
+     autómata mínimo completo es único salvo por los nombres de sus estados, dos autómatas que reconocen
+     el mismo lenguaje sobre el mismo alfabeto producen la misma huella, sin importar cómo se construyeron.
+        # Returns
+        Retorna un `u64` útil para eliminar autómatas repetidos de una colección. Dos huellas iguales no
+        garantizan lenguajes iguales (puede haber colisiones), y el valor puede cambiar entre versiones de Rust.
+    */
+    pub fn canonical_fingerprint(&self) -> u64 {
+        use std::hash::Hasher;
+
+        let canonical = self.complete_with_trap().minimize().canonicalize();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        canonical.alphabet.hash(&mut hasher);
+        canonical.start_state.hash(&mut hasher);
+        for state in &canonical.states {
+            state.is_accept.hash(&mut hasher);
+            state.transitions.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /**
+     Cambia el nombre de un estado. Las transiciones y el estado inicial se guardan por posición, por lo
+     que no hace falta actualizarlos.
+        # Arguments
+        * `old` - El nombre actual del estado.
+        * `new` - El nuevo nombre.
+        # Returns
+        Retorna un `DfaError` si `old` no existe o si ya hay otro estado llamado `new`.
+    */
+    pub fn rename_state(&mut self, old: &str, new: &str) -> Result<(), DfaError<S>> {
+        let state = self
+            .state_index(old)
+            .ok_or_else(|| DfaError::UnknownState(old.to_string()))?;
+        if old == new {
+            return Ok(());
+        }
+        if self.state_index(new).is_some() {
+            return Err(DfaError::DuplicateState(new.to_string()));
+        }
+        self.states[state].state = new.to_string();
+        Ok(())
+    }
+
+    /**
+     Agrega un estado sin transiciones a un autómata ya construido.
+        # Arguments
+        * `name` - El nombre del nuevo estado.
+        * `is_accept` - Si el estado es de aceptación.
+        # Returns
+        Retorna un `DfaError::DuplicateState` si ya existe un estado con ese nombre.
+    */
+    pub fn add_state(&mut self, name: &str, is_accept: bool) -> Result<(), DfaError<S>> {
+        if self.state_index(name).is_some() {
+            return Err(DfaError::DuplicateState(name.to_string()));
+        }
+        self.push_state(name, is_accept);
+        Ok(())
+    }
+
+    /**
+     Elimina un estado junto con todas las transiciones que salen de él o llegan a él. Los estados se
+     guardan por posición, así que las transiciones hacia los estados posteriores se reenumeran.
+        # Arguments
+        * `name` - El nombre del estado a eliminar.
+        # Returns
+        Retorna un `DfaError` si el estado no existe o si es el estado inicial.
+    */
+    pub fn remove_state(&mut self, name: &str) -> Result<(), DfaError<S>> {
+        let removed = self
+            .state_index(name)
+            .ok_or_else(|| DfaError::UnknownState(name.to_string()))?;
+        if removed == self.start_state {
+            return Err(DfaError::CannotRemoveStartState(name.to_string()));
+        }
+
+        self.states.remove(removed);
+        for state in &mut self.states {
+            for next in &mut state.transitions {
+                *next = match *next {
+                    Some(target) if target == removed => None,
+                    Some(target) if target > removed => Some(target - 1),
+                    other => other,
+                };
+            }
+        }
+        if self.start_state > removed {
+            self.start_state -= 1;
+        }
+        Ok(())
+    }
+
+    /**
+     Agrega una transición a un autómata ya construido.
+        # Arguments
+        * `from` - El nombre del estado de origen.
+        * `symbol` - El símbolo leído, que debe pertenecer al alfabeto.
+        * `to` - El nombre del estado destino.
+        # Returns
+        Retorna un `DfaError` si alguno de los estados no existe, si el símbolo no pertenece al alfabeto o
+        si ya hay una transición desde `from` con `symbol`, porque el autómata dejaría de ser determinista.
+    */
+    pub fn add_transition(&mut self, from: &str, symbol: S, to: &str) -> Result<(), DfaError<S>> {
+        let from_index = self
+            .state_index(from)
+            .ok_or_else(|| DfaError::UnknownState(from.to_string()))?;
+        let to_index = self
+            .state_index(to)
+            .ok_or_else(|| DfaError::UnknownState(to.to_string()))?;
+        let symbol_index = self
+            .symbol_index(&symbol)
+            .ok_or_else(|| DfaError::UnknownSymbol(symbol.clone()))?;
+
+        let next = &mut self.states[from_index].transitions[symbol_index];
+        if next.is_some() {
+            return Err(DfaError::DuplicateTransition {
+                state: from.to_string(),
+                symbol,
+            });
+        }
+        *next = Some(to_index);
+        Ok(())
+    }
+
+    /**
+     Elimina una transición; el autómata queda parcial si la tenía definida.
+        # Arguments
+        * `from` - El nombre del estado de origen.
+        * `symbol` - El símbolo de la transición.
+        # Returns
+        Retorna `true` si la transición existía y fue eliminada.
+    */
+    pub fn remove_transition(&mut self, from: &str, symbol: S) -> bool {
+        match (self.state_index(from), self.symbol_index(&symbol)) {
+            (Some(state), Some(symbol)) => self.states[state].transitions[symbol].take().is_some(),
+            _ => false,
+        }
+    }
+
+    /**
+     Aplica un homomorfismo que renombra los símbolos: cada transición con el símbolo `a` pasa a leer
+     `mapping[a]`, y el nuevo alfabeto es la imagen del anterior. Varios símbolos pueden tener la misma
+     imagen siempre que, desde cada estado, sus transiciones lleguen al mismo destino.
+        # Arguments
+        * `mapping` - La imagen de cada símbolo del alfabeto.
+        # Returns
+        Retorna un `DfaError::UnknownSymbol` si algún símbolo del alfabeto no tiene imagen, o un
+        `DfaError::DuplicateTransition` si dos símbolos con la misma imagen llevan a estados distintos
+        desde un mismo estado, porque el resultado no sería determinista.
+    */
+    pub fn map_alphabet(&self, mapping: &HashMap<S, S>) -> Result<DFA<S>, DfaError<S>> {
+        let images = self
+            .alphabet
+            .iter()
+            .map(|symbol| {
+                mapping
+                    .get(symbol)
+                    .cloned()
+                    .ok_or_else(|| DfaError::UnknownSymbol(symbol.clone()))
+            })
+            .collect::<Result<Vec<S>, _>>()?;
+
+        let mut dfa = DFA::with_alphabet(images.iter().cloned());
+        for state in &self.states {
+            let node = dfa.push_state(&state.state, state.is_accept);
+            for (image, next) in images.iter().zip(&state.transitions) {
+                if let Some(next) = *next {
+                    // La imagen siempre está en el nuevo alfabeto
+                    let symbol = dfa.symbol_index(image).unwrap();
+                    match dfa.states[node].transitions[symbol] {
+                        Some(existing) if existing != next => {
+                            return Err(DfaError::DuplicateTransition {
+                                state: state.state.clone(),
+                                symbol: image.clone(),
+                            })
+                        }
+                        _ => dfa.states[node].transitions[symbol] = Some(next),
+                    }
+                }
+            }
+        }
+        dfa.start_state = self.start_state;
+        Ok(dfa)
+    }
+
+    // Retorna las posiciones de los estados alcanzables desde el estado inicial, en orden de recorrido en anchura
+    fn reachable_indices(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.states.len()];
+        let mut order = Vec::new();
+        let mut queue = std::collections::VecDeque::new();
+
+        visited[self.start_state] = true;
+        queue.push_back(self.start_state);
+
+        while let Some(state) = queue.pop_front() {
+            for next in self.states[state].transitions.iter().flatten() {
+                if !visited[*next] {
+                    visited[*next] = true;
+                    queue.push_back(*next);
+                }
+            }
+            order.push(state);
+        }
+
+        order
+    }
+
+    /**
+     Calcula los estados alcanzables desde el estado inicial.
+        # Returns
+        Retorna un `HashSet<String>` con los nombres de los estados alcanzables.
+    */
+    pub fn reachable_states(&self) -> HashSet<String> {
+        self.reachable_indices()
+            .iter()
+            .map(|&state| self.states[state].state.clone())
+            .collect()
+    }
+
+    // Indica, para cada estado, si desde él se puede llegar a un estado de aceptación
+    fn coreachable_mask(&self) -> Vec<bool> {
+        let mut coreachable: Vec<bool> = self.states.iter().map(|state| state.is_accept).collect();
+
+        // Propagar hacia atrás hasta que no haya cambios
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, state) in self.states.iter().enumerate() {
+                if !coreachable[i]
+                    && state
+                        .transitions
+                        .iter()
+                        .flatten()
+                        .any(|&next| coreachable[next])
+                {
+                    coreachable[i] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        coreachable
+    }
+
+    // Retorna, para cada estado, la menor cantidad de símbolos para llegar a un estado de aceptación
+    // (`usize::MAX` si no se puede llegar)
+    #[cfg(feature = "rand")]
+    fn distances_to_accept(&self) -> Vec<usize> {
+        let mut distance: Vec<usize> = self
+            .states
+            .iter()
+            .map(|state| if state.is_accept { 0 } else { usize::MAX })
+            .collect();
+
+        // Propagar hacia atrás hasta que no haya cambios
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, state) in self.states.iter().enumerate() {
+                let best = state
+                    .transitions
+                    .iter()
+                    .flatten()
+                    .map(|&next| distance[next].saturating_add(1))
+                    .min()
+                    .unwrap_or(usize::MAX);
+                if best < distance[i] {
+                    distance[i] = best;
+                    changed = true;
+                }
+            }
+        }
+
+        distance
+    }
+
+    // Retorna los nombres de los estados desde los que se puede llegar a un estado de aceptación
+    pub fn coreachable_states(&self) -> HashSet<String> {
+        self.states
+            .iter()
+            .zip(self.coreachable_mask())
+            .filter(|(_, coreachable)| *coreachable)
+            .map(|(state, _)| state.state.clone())
+            .collect()
+    }
+
+    /**
+     Calcula los estados muertos, desde los que no se puede llegar a ningún estado de aceptación.
+        # Returns
+        Retorna un `HashSet<String>` con los nombres de los estados muertos.
+    */
+    pub fn dead_states(&self) -> HashSet<String> {
+        let coreachable = self.coreachable_states();
+        self.states()
+            .filter(|name| !coreachable.contains(*name))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /**
+     Revisa el autómata en busca de errores de modelado comunes que no impiden usarlo: estados
+     inalcanzables y estados muertos. Un estado inalcanzable solo se reporta como tal, aunque también
+     esté muerto. El estado trampa de un autómata completo se reporta como muerto.
+        # Returns
+        Retorna un `Vec<DfaWarning>` en el orden en que se declararon los estados; vacío si no hay advertencias.
+    */
+    pub fn warnings(&self) -> Vec<DfaWarning> {
+        let mut reachable = vec![false; self.states.len()];
+        for state in self.reachable_indices() {
+            reachable[state] = true;
+        }
+        let coreachable = self.coreachable_mask();
+
+        self.states
+            .iter()
+            .enumerate()
+            .filter_map(|(i, state)| {
+                if !reachable[i] {
+                    Some(DfaWarning::UnreachableState(state.state.clone()))
+                } else if !coreachable[i] {
+                    Some(DfaWarning::DeadState(state.state.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // Construye una copia con solo los estados marcados en `keep`, descartando las transiciones hacia los demás
+    fn retain_states(&self, keep: &[bool]) -> DFA<S> {
+        let mut new_index = vec![None; self.states.len()];
+        let mut dfa = DFA::with_alphabet(self.alphabet.clone());
+        for (i, state) in self.states.iter().enumerate() {
+            if keep[i] {
+                new_index[i] = Some(dfa.push_state(&state.state, state.is_accept));
+            }
+        }
+
+        for (i, state) in self.states.iter().enumerate() {
+            if let Some(from) = new_index[i] {
+                for (symbol, next) in state.transitions.iter().enumerate() {
+                    dfa.states[from].transitions[symbol] = next.and_then(|next| new_index[next]);
+                }
+            }
+        }
+        dfa.start_state = new_index[self.start_state].unwrap_or(0);
+
+        dfa
+    }
+
+    /**
+     Elimina los estados inalcanzables y, opcionalmente, los estados muertos.
+        # Arguments
+        * `remove_dead` - Si es `true` también se eliminan los estados desde los que no se puede aceptar.
+        # Returns
+        Retorna un `DFA` nuevo con los estados restantes y sus transiciones. El estado inicial siempre se conserva.
+    */
+    pub fn trim(&self, remove_dead: bool) -> DFA<S> {
+        let mut keep = vec![false; self.states.len()];
+        for state in self.reachable_indices() {
+            keep[state] = true;
+        }
+        if remove_dead {
+            for (keep, coreachable) in keep.iter_mut().zip(self.coreachable_mask()) {
+                *keep &= coreachable;
+            }
+        }
+        keep[self.start_state] = true;
+
+        self.retain_states(&keep)
+    }
+
+    /**
+     Fusiona los sumideros equivalentes sin minimizar por completo: todos los estados de rechazo cuyas
+     transiciones son lazos sobre sí mismos se reemplazan por uno solo, y lo mismo con los de aceptación.
+     Es útil para limpiar los estados trampa repetidos que dejan el complemento o el producto.
+        # Returns
+        Retorna un `DFA` donde cada grupo de sumideros queda representado por el primero que se declaró.
+    */
+    pub fn merge_sinks(&self) -> DFA<S> {
+        // representative[i] = estado que reemplaza a i
+        let mut representative: Vec<usize> = (0..self.states.len()).collect();
+        let mut first_sink: [Option<usize>; 2] = [None, None];
+        for (i, state) in self.states.iter().enumerate() {
+            let is_sink = state.transitions.iter().all(|&next| next == Some(i));
+            if is_sink && !self.alphabet.is_empty() {
+                representative[i] = *first_sink[state.is_accept as usize].get_or_insert(i);
+            }
+        }
+
+        let mut new_index = vec![0; self.states.len()];
+        let mut dfa = DFA::with_alphabet(self.alphabet.clone());
+        for (i, state) in self.states.iter().enumerate() {
+            if representative[i] == i {
+                new_index[i] = dfa.push_state(&state.state, state.is_accept);
+            }
+        }
+        for (i, state) in self.states.iter().enumerate() {
+            if representative[i] == i {
+                dfa.states[new_index[i]].transitions = state
+                    .transitions
+                    .iter()
+                    .map(|next| next.map(|next| new_index[representative[next]]))
+                    .collect();
+            }
+        }
+        dfa.start_state = new_index[representative[self.start_state]];
+        dfa
+    }
+
+    /**
+     Decide si el lenguaje del autómata es vacío.
+        # Returns
+        Retorna `true` si ningún estado de aceptación es alcanzable desde el estado inicial.
+    */
+    pub fn is_empty(&self) -> bool {
+        !self
+            .reachable_indices()
+            .iter()
+            .any(|&state| self.states[state].is_accept)
+    }
+
+    /**
+     Decide si el autómata acepta todas las palabras sobre su alfabeto. El autómata se completa con un
+     estado trampa, de modo que una transición faltante cuenta como un estado de rechazo alcanzable.
+        # Returns
+        Retorna `true` si todos los estados alcanzables del autómata completo son de aceptación.
+    */
+    pub fn is_universal(&self) -> bool {
+        let complete = self.complete_with_trap();
+        complete
+            .reachable_indices()
+            .iter()
+            .all(|&state| complete.states[state].is_accept)
+    }
+
+    /**
+     Decide si el autómata acepta una cantidad finita de palabras.
+     Solo se consideran los estados vivos, alcanzables y desde los que se puede aceptar: el lenguaje es
+     infinito si y solo si alguna componente fuertemente conexa de estados vivos tiene un ciclo.
+        # Returns
+        Retorna `true` si el lenguaje es finito, incluido el lenguaje vacío.
+    */
+    pub fn is_finite(&self) -> bool {
+        let coreachable = self.coreachable_mask();
+        let mut live = vec![false; self.states.len()];
+        for state in self.reachable_indices() {
+            live[state] = coreachable[state];
+        }
+
+        // Todos los estados de una componente son vivos si lo es uno de ellos, porque están en un ciclo
+        !self.scc_indices().iter().any(|component| {
+            let state = component[0];
+            let has_cycle =
+                component.len() > 1 || self.states[state].transitions.contains(&Some(state));
+            has_cycle && live[state]
+        })
+    }
+
+    /**
+     Calcula las componentes fuertemente conexas del grafo de transiciones con el algoritmo de Tarjan.
+     Cada componente agrupa estados que pueden alcanzarse mutuamente; un estado sin ciclos forma una
+     componente de un solo estado.
+        # Returns
+        Retorna los nombres de los estados de cada componente, en el orden en que fueron declarados. Las
+        componentes aparecen en orden topológico inverso: ninguna tiene transiciones hacia las siguientes.
+    */
+    pub fn sccs(&self) -> Vec<Vec<String>> {
+        self.scc_indices()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|state| self.states[state].state.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Algoritmo de Tarjan sin recursión; retorna las componentes como índices ordenados
+    fn scc_indices(&self) -> Vec<Vec<usize>> {
+        let n = self.states.len();
+        let mut index = vec![usize::MAX; n];
+        let mut low_link = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..n {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            // Cada marco guarda el estado y la posición de la próxima transición a explorar
+            let mut frames = vec![(root, 0)];
+            index[root] = next_index;
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (state, ref mut position)) = frames.last_mut() {
+                let transitions = &self.states[state].transitions;
+                if *position < transitions.len() {
+                    let next = transitions[*position];
+                    *position += 1;
+                    match next {
+                        Some(next) if index[next] == usize::MAX => {
+                            index[next] = next_index;
+                            low_link[next] = next_index;
+                            next_index += 1;
+                            stack.push(next);
+                            on_stack[next] = true;
+                            frames.push((next, 0));
+                        }
+                        Some(next) if on_stack[next] => {
+                            low_link[state] = low_link[state].min(index[next]);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                // Todas las transiciones exploradas: cerrar la componente si `state` es su raíz
+                frames.pop();
+                if let Some(&(parent, _)) = frames.last() {
+                    low_link[parent] = low_link[parent].min(low_link[state]);
+                }
+                if low_link[state] == index[state] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == state {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /**
+     Cuenta las palabras aceptadas de una longitud exacta mediante programación dinámica.
+        # Arguments
+        * `length` - La longitud de las palabras a contar.
+        # Returns
+        Retorna un `u64` con la cantidad de palabras de longitud `length` aceptadas. Si la cantidad no
+        cabe en un `u64` se retorna `u64::MAX`.
+    */
+    pub fn count_accepted(&self, length: usize) -> u64 {
+        // counts[i] = cantidad de palabras de la longitud actual que llevan al estado i
+        let mut counts = vec![0u64; self.states.len()];
+        counts[self.start_state] = 1;
+
+        for _ in 0..length {
+            counts = self.count_step(&counts);
+        }
+
+        self.accepted_count(&counts)
+    }
+
+    /**
+     Cuenta las palabras aceptadas de cada longitud desde 0 hasta `max_len`, con la misma programación
+     dinámica que `count_accepted` pero acumulando todas las longitudes en una sola pasada.
+        # Arguments
+        * `max_len` - La longitud máxima de las palabras a contar.
+        # Returns
+        Retorna un `Vec<u64>` de `max_len + 1` elementos, donde la posición `n` tiene la cantidad de
+        palabras aceptadas de longitud `n`; las cantidades que no caben en un `u64` quedan en `u64::MAX`.
+    */
+    pub fn length_histogram(&self, max_len: usize) -> Vec<u64> {
+        let mut counts = vec![0u64; self.states.len()];
+        counts[self.start_state] = 1;
+
+        let mut histogram = vec![self.accepted_count(&counts)];
+        for _ in 0..max_len {
+            counts = self.count_step(&counts);
+            histogram.push(self.accepted_count(&counts));
+        }
+        histogram
+    }
+
+    // Avanza un símbolo: a partir de cuántas palabras llegan a cada estado, calcula cuántas llegan con una más.
+    // Las sumas se saturan en `u64::MAX` en lugar de desbordarse
+    fn count_step(&self, counts: &[u64]) -> Vec<u64> {
+        let mut next_counts = vec![0u64; self.states.len()];
+        for (i, state) in self.states.iter().enumerate() {
+            if counts[i] == 0 {
+                continue;
+            }
+            for &next in state.transitions.iter().flatten() {
+                next_counts[next] = next_counts[next].saturating_add(counts[i]);
+            }
+        }
+        next_counts
+    }
+
+    // Suma las palabras que terminan en estados de aceptación
+    fn accepted_count(&self, counts: &[u64]) -> u64 {
+        self.states
+            .iter()
+            .zip(counts)
+            .filter(|(state, _)| state.is_accept)
+            .fold(0u64, |total, (_, &count)| total.saturating_add(count))
+    }
+
+    /**
+     Calcula las clases de equivalencia de Myhill–Nerode de los estados con el algoritmo de llenado de
+     tabla: se marcan como distinguibles los pares en que solo uno acepta y luego, repetidamente, los
+     pares que con algún símbolo llegan a un par ya marcado. Las transiciones faltantes se tratan como
+     si fueran a un estado trampa.
+        # Returns
+        Retorna una partición de los estados; cada clase agrupa estados indistinguibles y respeta el
+        orden en que fueron declarados.
+    */
+    pub fn equivalence_classes(&self) -> Vec<Vec<String>> {
+        let complete = self.complete_with_trap();
+        let n = complete.states.len();
+        let next =
+            |state: usize, symbol: usize| complete.states[state].transitions[symbol].unwrap();
+
+        // distinguishable[p][q] indica si los estados p y q son distinguibles
+        let mut distinguishable: Vec<Vec<bool>> = complete
+            .states
+            .iter()
+            .map(|p| {
+                complete
+                    .states
+                    .iter()
+                    .map(|q| p.is_accept != q.is_accept)
+                    .collect()
+            })
+            .collect();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for p in 0..n {
+                for q in 0..p {
+                    if distinguishable[p][q] {
+                        continue;
+                    }
+                    if (0..complete.alphabet.len())
+                        .any(|symbol| distinguishable[next(p, symbol)][next(q, symbol)])
+                    {
+                        distinguishable[p][q] = true;
+                        distinguishable[q][p] = true;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        // Agrupar los estados originales; el estado trampa agregado, si lo hay, queda fuera
+        let mut assigned = vec![false; self.states.len()];
+        let mut classes = Vec::new();
+        for p in 0..self.states.len() {
+            if assigned[p] {
+                continue;
+            }
+            let mut class = Vec::new();
+            for q in p..self.states.len() {
+                if !assigned[q] && !distinguishable[p][q] {
+                    assigned[q] = true;
+                    class.push(self.states[q].state.clone());
+                }
+            }
+            classes.push(class);
+        }
+        classes
+    }
+
+    /**
+     Construye el autómata mínimo equivalente usando el algoritmo de Hopcroft.
+     Primero se descartan los estados inalcanzables y, si el autómata es parcial, se completa
+     temporalmente con un estado trampa que se elimina al final.
+        # Returns
+        Retorna un `DFA` mínimo cuyos estados representan las clases de equivalencia del original.
+    */
+    pub fn minimize(&self) -> DFA<S> {
+        let was_complete = self.is_complete();
+        let complete = self.complete_with_trap();
+
+        let symbols = &complete.alphabet;
+
+        // Se trabaja solo con los estados alcanzables, numerados según el recorrido
+        let order = complete.reachable_indices();
+        let nodes: Vec<&Node> = order.iter().map(|&state| &complete.states[state]).collect();
+        let mut index = vec![0; complete.states.len()];
+        for (i, &state) in order.iter().enumerate() {
+            index[state] = i;
+        }
+
+        // delta[estado][símbolo] e inversa inverse[símbolo][destino] = orígenes
+        let delta: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|node| {
+                node.transitions
+                    .iter()
+                    .map(|next| index[next.unwrap()])
+                    .collect()
+            })
+            .collect();
+        let mut inverse = vec![vec![Vec::new(); nodes.len()]; symbols.len()];
+        for (from, row) in delta.iter().enumerate() {
+            for (symbol, &to) in row.iter().enumerate() {
+                inverse[symbol][to].push(from);
+            }
+        }
+
+        // Partición inicial: estados de aceptación y de rechazo
+        let (accepting, rejecting): (Vec<usize>, Vec<usize>) =
+            (0..nodes.len()).partition(|&i| nodes[i].is_accept);
+        let mut blocks: Vec<Vec<usize>> = Vec::new();
+        let mut block_of = vec![0; nodes.len()];
+        let mut worklist: Vec<usize> = Vec::new();
+
+        for block in [accepting, rejecting] {
+            if !block.is_empty() {
+                for &state in &block {
+                    block_of[state] = blocks.len();
+                }
+                blocks.push(block);
+            }
+        }
+        if blocks.len() == 2 {
+            worklist.push(if blocks[0].len() <= blocks[1].len() {
+                0
+            } else {
+                1
+            });
+        }
+        let mut pending: HashSet<usize> = worklist.iter().copied().collect();
+
+        // Refinar la partición hasta que no queden divisores
+        while let Some(splitter) = worklist.pop() {
+            pending.remove(&splitter);
+            let members = blocks[splitter].clone();
+
+            for predecessors in &inverse {
+                let preimage: HashSet<usize> = members
+                    .iter()
+                    .flat_map(|&state| predecessors[state].iter().copied())
+                    .collect();
+
+                let touched: HashSet<usize> = preimage.iter().map(|&s| block_of[s]).collect();
+                for block in touched {
+                    let (inside, outside): (Vec<usize>, Vec<usize>) = blocks[block]
+                        .iter()
+                        .partition(|state| preimage.contains(state));
+                    if outside.is_empty() {
+                        continue;
+                    }
+
+                    let new_block = blocks.len();
+                    for &state in &outside {
+                        block_of[state] = new_block;
+                    }
+                    let smaller = if inside.len() <= outside.len() {
+                        block
+                    } else {
+                        new_block
+                    };
+                    blocks[block] = inside;
+                    blocks.push(outside);
+
+                    if pending.contains(&block) {
+                        worklist.push(new_block);
+                        pending.insert(new_block);
+                    } else {
+                        worklist.push(smaller);
+                        pending.insert(smaller);
+                    }
+                }
+            }
+        }
+
+        // Ordenar las clases según la primera aparición de sus estados en el recorrido
+        let mut class_order: Vec<usize> = Vec::new();
+        for &block in &block_of {
+            if !class_order.contains(&block) {
+                class_order.push(block);
+            }
+        }
+
+        // Si el original era parcial se descarta la clase sin salida hacia la aceptación
+        let dead_class = if was_complete {
+            None
+        } else {
+            class_order.iter().copied().find(|&block| {
+                blocks[block].iter().all(|&s| !nodes[s].is_accept)
+                    && blocks[block]
+                        .iter()
+                        .all(|&s| delta[s].iter().all(|&to| block_of[to] == block))
+            })
+        };
+
+        let mut used_names: HashSet<String> = HashSet::new();
+        let mut class_nodes: HashMap<usize, usize> = HashMap::new();
+        let mut dfa = DFA::with_alphabet(complete.alphabet.clone());
+        for &block in &class_order {
+            if Some(block) == dead_class {
+                continue;
+            }
+            let mut names: Vec<String> = blocks[block]
+                .iter()
+                .map(|&s| nodes[s].state.clone())
+                .collect();
+            names.sort();
+            let mut name = names.concat();
+            while !used_names.insert(name.clone()) {
+                name.push('\'');
+            }
+            let node = dfa.push_state(&name, nodes[blocks[block][0]].is_accept);
+            class_nodes.insert(block, node);
+        }
+
+        for &block in &class_order {
+            if let Some(&node) = class_nodes.get(&block) {
+                let representative = blocks[block][0];
+                for symbol in 0..symbols.len() {
+                    let target = block_of[delta[representative][symbol]];
+                    dfa.states[node].transitions[symbol] = class_nodes.get(&target).copied();
+                }
+            }
+        }
+
+        let start_block = block_of[index[complete.start_state]];
+        dfa.start_state = match class_nodes.get(&start_block) {
+            Some(&start) => start,
+            // El lenguaje es vacío y el estado inicial era la clase muerta
+            None => dfa.push_state(complete.start_name(), false),
+        };
+
+        dfa
+    }
+
+    /**
+     Construye un NFA que reconoce el lenguaje inverso, invirtiendo la dirección de cada transición.
+     Los estados de aceptación pasan a ser los estados iniciales y el estado inicial pasa a ser el
+     único estado de aceptación.
+        # Returns
+        Retorna un `NFA` con los mismos nombres de estados y el mismo alfabeto.
+    */
+    pub fn reverse(&self) -> NFA<S> {
+        let mut nfa = NFA::new();
+        nfa.alphabet = self.alphabet.iter().cloned().collect();
+
+        for (i, state) in self.states.iter().enumerate() {
+            nfa.add_state(&state.state, i == self.start_state);
+            if state.is_accept {
+                nfa.add_start_state(&state.state);
+            }
+        }
+
+        for state in &self.states {
+            for (symbol, next) in self.alphabet.iter().zip(&state.transitions) {
+                if let Some(next) = next {
+                    nfa.add_transition(&self.states[*next].state, symbol.clone(), &state.state);
+                }
+            }
+        }
+
+        nfa
+    }
+
+    /**
+     Construye el autómata mínimo equivalente con el algoritmo de Brzozowski:
+     invertir, determinizar, invertir y determinizar de nuevo.
+        # Returns
+        Retorna un `DFA` mínimo y sin estados inalcanzables. Como la construcción de subconjuntos omite
+        el conjunto vacío, el resultado puede ser parcial.
+    */
+    pub fn minimize_brzozowski(&self) -> DFA<S> {
+        self.reverse().to_dfa().reverse().to_dfa()
+    }
+
+    /**
+     Calcula una longitud de bombeo para el lema de bombeo: la cantidad de estados del autómata mínimo
+     (sin estado trampa). Cualquier palabra aceptada de esa longitud o más recorre algún estado dos veces
+     antes de terminar, así que puede bombearse.
+        # Returns
+        Retorna *una* longitud de bombeo válida, que no es necesariamente la más pequeña posible.
+    */
+    pub fn pumping_length(&self) -> usize {
+        // `minimize` conserva el estado trampa si el autómata era completo
+        self.minimize().trim(true).state_count()
+    }
+
+    /**
+     Construye el autómata que acepta exactamente las palabras que este rechaza.
+     El autómata se completa internamente con un estado trampa antes de invertir la aceptación,
+     por lo que no es necesario llamar a `complete_with_trap` previamente.
+        # Returns
+        Retorna un `DFA` sobre el mismo alfabeto que reconoce el lenguaje complemento.
+    */
+    pub fn complement(&self) -> DFA<S> {
+        let mut dfa = self.complete_with_trap();
+
+        for state in &mut dfa.states {
+            state.is_accept = !state.is_accept;
+        }
+
+        dfa
+    }
+
+    /**
+     Construye el autómata producto que acepta la intersección de ambos lenguajes.
+        # Arguments
+        * `other` - El otro autómata; su alfabeto puede ser distinto.
+        # Returns
+        Retorna un `DFA` cuyos estados son pares `p,q` y que acepta cuando ambos componentes aceptan.
+    */
+    pub fn intersection(&self, other: &DFA<S>) -> DFA<S> {
+        self.product_with(other, |a, b| a && b)
+    }
+
+    /**
+     Construye el autómata producto que acepta la unión de ambos lenguajes.
+        # Arguments
+        * `other` - El otro autómata; su alfabeto puede ser distinto.
+        # Returns
+        Retorna un `DFA` cuyos estados son pares `p,q` y que acepta cuando alguno de los componentes acepta.
+    */
+    pub fn union(&self, other: &DFA<S>) -> DFA<S> {
+        self.product_with(other, |a, b| a || b)
+    }
+
+    /**
+     Construye en un solo paso el producto de varios autómatas que acepta la intersección de todos sus
+     lenguajes, en lugar de encadenar `intersection` de a pares. Como en `intersection`, el producto usa
+     la unión de los alfabetos.
+        # Arguments
+        * `dfas` - Los autómatas a combinar; sus alfabetos pueden ser distintos.
+        # Returns
+        Retorna un `DFA` cuyos estados se nombran `p,q,r` y que acepta cuando todos los componentes
+        aceptan, o `DfaError::EmptyProduct` si la lista está vacía.
+    */
+    pub fn intersection_many(dfas: &[DFA<S>]) -> Result<DFA<S>, DfaError<S>> {
+        DFA::product_many(dfas, |accepts| accepts.iter().all(|&accept| accept))
+    }
+
+    /**
+     Construye en un solo paso el producto de varios autómatas que acepta la unión de todos sus
+     lenguajes, en lugar de encadenar `union` de a pares. Como en `union`, el producto usa la unión de
+     los alfabetos.
+        # Arguments
+        * `dfas` - Los autómatas a combinar; sus alfabetos pueden ser distintos.
+        # Returns
+        Retorna un `DFA` cuyos estados se nombran `p,q,r` y que acepta cuando algún componente acepta, o
+        `DfaError::EmptyProduct` si la lista está vacía.
+    */
+    pub fn union_many(dfas: &[DFA<S>]) -> Result<DFA<S>, DfaError<S>> {
+        DFA::product_many(dfas, |accepts| accepts.iter().any(|&accept| accept))
+    }
+
+    // Producto de todos los autómatas a la vez, como `product_with`: cada uno se extiende a la unión de
+    // los alfabetos y se completa con un estado trampa, y solo se generan las tuplas alcanzables
+    fn product_many(
+        dfas: &[DFA<S>],
+        accept: impl Fn(&[bool]) -> bool,
+    ) -> Result<DFA<S>, DfaError<S>> {
+        if dfas.is_empty() {
+            return Err(DfaError::EmptyProduct);
+        }
+        let symbols: Vec<S> = dfas
+            .iter()
+            .flat_map(|dfa| dfa.alphabet.iter().cloned())
+            .collect();
+        let components: Vec<DFA<S>> = dfas
+            .iter()
+            .map(|dfa| {
+                dfa.extend_alphabet(symbols.iter().cloned())
+                    .complete_with_trap()
+            })
+            .collect();
+
+        let mut dfa = DFA::with_alphabet(components[0].alphabet.clone());
+        let add_tuple = |dfa: &mut DFA<S>, tuple: &[usize]| {
+            let states: Vec<&Node> = components
+                .iter()
+                .zip(tuple)
+                .map(|(component, &state)| &component.states[state])
+                .collect();
+            let names: Vec<&str> = states.iter().map(|state| state.state.as_str()).collect();
+            let accepts: Vec<bool> = states.iter().map(|state| state.is_accept).collect();
+            dfa.push_state(&names.join(","), accept(&accepts))
+        };
+
+        let start: Vec<usize> = components.iter().map(|dfa| dfa.start_state).collect();
+        dfa.start_state = add_tuple(&mut dfa, &start);
+        let mut tuples: HashMap<Vec<usize>, usize> = HashMap::new();
+        tuples.insert(start.clone(), dfa.start_state);
+
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back((start, dfa.start_state));
+
+        while let Some((tuple, node)) = queue.pop_front() {
+            for symbol in 0..dfa.alphabet.len() {
+                let next_tuple: Vec<usize> = components
+                    .iter()
+                    .zip(&tuple)
+                    .map(|(component, &state)| component.states[state].transitions[symbol].unwrap())
+                    .collect();
+
+                let next = match tuples.get(&next_tuple) {
+                    Some(&next) => next,
+                    None => {
+                        let next = add_tuple(&mut dfa, &next_tuple);
+                        tuples.insert(next_tuple.clone(), next);
+                        queue.push_back((next_tuple, next));
+                        next
+                    }
+                };
+                dfa.states[node].transitions[symbol] = Some(next);
+            }
+        }
+
+        Ok(dfa)
+    }
+
+    /**
+     Construye el autómata producto que acepta las palabras de este lenguaje que no están en el de `other`.
+        # Arguments
+        * `other` - El autómata cuyo lenguaje se resta; su alfabeto puede ser distinto.
+        # Returns
+        Retorna un `DFA` cuyos estados son pares `p,q` y que acepta cuando solo el primer componente acepta.
+    */
+    pub fn difference(&self, other: &DFA<S>) -> DFA<S> {
+        self.product_with(other, |a, b| a && !b)
+    }
+
+    /**
+     Construye el autómata producto que acepta las palabras que están en exactamente uno de los dos lenguajes.
+        # Arguments
+        * `other` - El otro autómata; su alfabeto puede ser distinto.
+        # Returns
+        Retorna un `DFA` cuyos estados son pares `p,q` y que acepta cuando solo uno de los componentes acepta.
+    */
+    pub fn symmetric_difference(&self, other: &DFA<S>) -> DFA<S> {
+        self.product_with(other, |a, b| a != b)
+    }
+
+    /**
+     Construye un autómata que acepta la concatenación `L(self) · L(other)`. Se arma un NFA con una
+     transición épsilon desde cada estado de aceptación de este autómata hacia el inicial de `other`,
+     y luego se determiniza.
+        # Arguments
+        * `other` - El autómata cuyo lenguaje va a continuación; su alfabeto puede ser distinto.
+        # Returns
+        Retorna un `DFA` sobre la unión de ambos alfabetos. Sus estados se nombran con los subconjuntos
+        del NFA, donde los estados de cada autómata llevan el prefijo `1.` o `2.`.
+    */
+    pub fn concatenate(&self, other: &DFA<S>) -> DFA<S> {
+        let mut nfa = NFA::new();
+        self.copy_into_nfa(&mut nfa, "1.");
+        other.copy_into_nfa(&mut nfa, "2.");
+
+        let second_start = format!("2.{}", other.states[other.start_state].state);
+        nfa.add_start_state(&format!("1.{}", self.states[self.start_state].state));
+        for state in self.states.iter().filter(|state| state.is_accept) {
+            nfa.add_epsilon_transition(&format!("1.{}", state.state), &second_start);
+        }
+        for state in other.states.iter().filter(|state| state.is_accept) {
+            nfa.add_state(&format!("2.{}", state.state), true);
+        }
+
+        nfa.to_dfa()
+    }
+
+    /**
+     Construye un autómata que acepta la clausura de Kleene `L(self)*`. Se arma un NFA con un nuevo
+     estado inicial de aceptación, unido por una transición épsilon al inicial original, y con
+     transiciones épsilon desde cada estado de aceptación de vuelta al inicial original; luego se determiniza.
+        # Returns
+        Retorna un `DFA` que siempre acepta la palabra vacía. Sus estados se nombran con los subconjuntos
+        del NFA, donde los estados originales llevan el prefijo `1.` y el nuevo inicial se llama `inicio`.
+    */
+    pub fn kleene_star(&self) -> DFA<S> {
+        let mut nfa = NFA::new();
+        self.copy_into_nfa(&mut nfa, "1.");
+
+        let start = format!("1.{}", self.states[self.start_state].state);
+        nfa.add_state("inicio", true);
+        nfa.add_start_state("inicio");
+        nfa.add_epsilon_transition("inicio", &start);
+        for state in self.states.iter().filter(|state| state.is_accept) {
+            nfa.add_state(&format!("1.{}", state.state), true);
+            nfa.add_epsilon_transition(&format!("1.{}", state.state), &start);
+        }
+
+        nfa.to_dfa()
+    }
+
+    // Copia los estados (sin aceptación), el alfabeto y las transiciones a un NFA, anteponiendo `prefix` a cada nombre
+    fn copy_into_nfa(&self, nfa: &mut NFA<S>, prefix: &str) {
+        nfa.alphabet.extend(self.alphabet.iter().cloned());
+        for state in &self.states {
+            nfa.add_state(&format!("{}{}", prefix, state.state), false);
+            for (symbol, next) in self.alphabet.iter().zip(&state.transitions) {
+                if let Some(next) = next {
+                    nfa.add_transition(
+                        &format!("{}{}", prefix, state.state),
+                        symbol.clone(),
+                        &format!("{}{}", prefix, self.states[*next].state),
+                    );
+                }
+            }
+        }
+    }
+
+    /**
+     Decide si dos autómatas reconocen el mismo lenguaje, verificando que su diferencia simétrica sea vacía.
+     Los autómatas parciales se completan internamente.
+        # Arguments
+        * `other` - El otro autómata; su alfabeto puede ser distinto.
+        # Returns
+        Retorna `true` si ambos autómatas aceptan exactamente las mismas palabras.
+    */
+    pub fn equivalent(&self, other: &DFA<S>) -> bool {
+        self.symmetric_difference(other).is_empty()
+    }
+
+    /**
+     Decide si el lenguaje de `other` está contenido en el de este autómata, verificando que
+     `L(other) \ L(self)` sea vacío.
+        # Arguments
+        * `other` - El autómata cuyo lenguaje se compara; su alfabeto puede ser distinto.
+        # Returns
+        Retorna `true` si toda palabra aceptada por `other` también es aceptada por este autómata.
+    */
+    pub fn contains_language(&self, other: &DFA<S>) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    // Producto cartesiano sobre la unión de los alfabetos: ambos autómatas se completan con un estado
+    // trampa, los pares alcanzables se nombran `p,q` y `accept` decide la aceptación de cada par
+    fn product_with(&self, other: &DFA<S>, accept: impl Fn(bool, bool) -> bool) -> DFA<S> {
+        let left = self
+            .extend_alphabet(other.alphabet.iter().cloned())
+            .complete_with_trap();
+        let right = other
+            .extend_alphabet(self.alphabet.iter().cloned())
+            .complete_with_trap();
+
+        let mut dfa = DFA::with_alphabet(left.alphabet.clone());
+        let add_pair = |dfa: &mut DFA<S>, p: usize, q: usize| {
+            let (p, q) = (&left.states[p], &right.states[q]);
+            dfa.push_state(
+                &format!("{},{}", p.state, q.state),
+                accept(p.is_accept, q.is_accept),
+            )
+        };
+
+        let start = (left.start_state, right.start_state);
+        dfa.start_state = add_pair(&mut dfa, start.0, start.1);
+        let mut pairs: HashMap<(usize, usize), usize> = HashMap::new();
+        pairs.insert(start, dfa.start_state);
+
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back((start.0, start.1, dfa.start_state));
+
+        while let Some((p, q, node)) = queue.pop_front() {
+            for symbol in 0..dfa.alphabet.len() {
+                let next_p = left.states[p].transitions[symbol].unwrap();
+                let next_q = right.states[q].transitions[symbol].unwrap();
+
+                let next = match pairs.get(&(next_p, next_q)) {
+                    Some(&next) => next,
+                    None => {
+                        let next = add_pair(&mut dfa, next_p, next_q);
+                        pairs.insert((next_p, next_q), next);
+                        queue.push_back((next_p, next_q, next));
+                        next
+                    }
+                };
+                dfa.states[node].transitions[symbol] = Some(next);
+            }
+        }
+
+        dfa
+    }
+
+    /**
+     Genera la representación del autómata en el lenguaje DOT de Graphviz.
+     Las transiciones entre el mismo par de estados se agrupan en una sola arista.
+        # Returns
+        Retorna un `String` que puede pasarse directamente a `dot -Tpng`.
+    */
+    pub fn to_dot(&self) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('"', "\\\""));
+        let mut dot = String::from("digraph DFA {\n    rankdir=LR;\n");
+
+        dot.push_str("    __start [shape=none, label=\"\"];\n");
+        let states = self.sorted_state_indices();
+        for (_, state) in &states {
+            let shape = if state.is_accept {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            dot.push_str(&format!("    {} [shape={}];\n", quote(&state.state), shape));
+        }
+        dot.push_str(&format!("    __start -> {};\n", quote(self.start_name())));
+
+        for (_, state) in &states {
+            // Agrupar los símbolos por estado destino, en orden alfabético de los destinos
+            for (target_index, target) in &states {
+                let labels: Vec<String> = self
+                    .alphabet
+                    .iter()
+                    .zip(&state.transitions)
+                    .filter(|(_, next)| **next == Some(*target_index))
+                    .map(|(symbol, _)| symbol.to_string())
+                    .collect();
+                if !labels.is_empty() {
+                    dot.push_str(&format!(
+                        "    {} -> {} [label={}];\n",
+                        quote(&state.state),
+                        quote(&target.state),
+                        quote(&labels.join(","))
+                    ));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /**
+     Genera un `tikzpicture` con la biblioteca `automata` de TikZ para incluirlo en un documento LaTeX.
+     Los estados se ubican en una fila, ordenados por nombre, y las transiciones entre el mismo par de
+     estados se agrupan en una sola arista. El documento debe cargar `\usetikzlibrary{automata}`.
+        # Returns
+        Retorna un `String` con el entorno `tikzpicture` completo.
+    */
+    pub fn to_tikz(&self) -> String {
+        let states = self.sorted_state_indices();
+        // Identificador de TikZ de cada estado según su posición en la fila
+        let mut node_ids = vec![0; self.states.len()];
+        for (position, (index, _)) in states.iter().enumerate() {
+            node_ids[*index] = position;
+        }
+
+        let mut tikz = String::from("\\begin{tikzpicture}[shorten >=1pt, auto, >=stealth]\n");
+        for (position, (index, state)) in states.iter().enumerate() {
+            let mut options = String::from("state");
+            if *index == self.start_state {
+                options.push_str(", initial");
+            }
+            if state.is_accept {
+                options.push_str(", accepting");
+            }
+            tikz.push_str(&format!(
+                "    \\node[{}] (s{}) at ({}, 0) {{{}}};\n",
+                options,
+                position,
+                position as f64 * 2.5,
+                latex_escape(&state.state)
+            ));
+        }
+
+        for (from, state) in &states {
+            for (to, _) in &states {
+                let labels: Vec<String> = self
+                    .alphabet
+                    .iter()
+                    .zip(&state.transitions)
+                    .filter(|(_, next)| **next == Some(*to))
+                    .map(|(symbol, _)| latex_escape(&symbol.to_string()))
+                    .collect();
+                if labels.is_empty() {
+                    continue;
+                }
+                // Los lazos van sobre el estado y las aristas entre estados se curvan para que las de
+                // ida y vuelta no se superpongan
+                let style = if from == to {
+                    "loop above"
+                } else {
+                    "bend left"
+                };
+                tikz.push_str(&format!(
+                    "    \\path[->] (s{}) edge [{}] node {{{}}} (s{});\n",
+                    node_ids[*from],
+                    style,
+                    labels.join(", "),
+                    node_ids[*to]
+                ));
+            }
+        }
+
+        tikz.push_str("\\end{tikzpicture}\n");
+        tikz
+    }
+
+    /**
+     Genera un diagrama `stateDiagram-v2` de Mermaid, que GitHub y muchas herramientas de documentación
+     muestran directamente en Markdown. Cada estado recibe un identificador `s{n}` según su posición al
+     ordenarlos por nombre, los de aceptación llevan la clase `accepting` y las transiciones entre el
+     mismo par de estados se agrupan en una sola arista.
+        # Returns
+        Retorna un `String` con el diagrama, sin el bloque de código `mermaid` que lo rodea en Markdown.
+    */
+    pub fn to_mermaid(&self) -> String {
+        let states = self.sorted_state_indices();
+        let mut node_ids = vec![0; self.states.len()];
+        for (position, (index, _)) in states.iter().enumerate() {
+            node_ids[*index] = position;
+        }
+
+        let mut mermaid = String::from("stateDiagram-v2\n    direction LR\n");
+        for (position, (_, state)) in states.iter().enumerate() {
+            mermaid.push_str(&format!(
+                "    state \"{}\" as s{}\n",
+                state.state.replace('"', "#quot;"),
+                position
+            ));
+        }
+        mermaid.push_str(&format!("    [*] --> s{}\n", node_ids[self.start_state]));
+
+        for (from, state) in &states {
+            for (to, _) in &states {
+                let labels: Vec<String> = self
+                    .alphabet
+                    .iter()
+                    .zip(&state.transitions)
+                    .filter(|(_, next)| **next == Some(*to))
+                    .map(|(symbol, _)| symbol.to_string())
+                    .collect();
+                if !labels.is_empty() {
+                    mermaid.push_str(&format!(
+                        "    s{} --> s{}: {}\n",
+                        node_ids[*from],
+                        node_ids[*to],
+                        labels.join(", ")
+                    ));
+                }
+            }
+        }
+
+        let accepting: Vec<String> = states
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, state))| state.is_accept)
+            .map(|(position, _)| format!("s{}", position))
+            .collect();
+        if !accepting.is_empty() {
+            mermaid.push_str("    classDef accepting stroke-width:4px,font-weight:bold\n");
+            mermaid.push_str(&format!("    class {} accepting\n", accepting.join(",")));
+        }
+        mermaid
+    }
+
+    /**
+     Serializa el autómata en el mismo formato de texto que lee `from_string`.
+     Los estados y los símbolos se ordenan para que la salida sea estable. Los nombres que contienen
+     comas, llaves, paréntesis, comillas o `->` (como los `p,e` de `intersection`) se escriben entre
+     comillas para que puedan leerse de nuevo, igual que los tokens con comas, llaves, paréntesis o
+     espacios.
+        # Returns
+        Retorna un `String` con las líneas `alphabet=`, `state=`, `start_state=`, `F=` y las transiciones.
+    */
+    pub fn to_string_format(&self) -> String {
+        let states = self.sorted_states();
+
+        let names: Vec<String> = states.iter().map(|s| quote_state_name(&s.state)).collect();
+        let accepting: Vec<String> = states
+            .iter()
+            .filter(|s| s.is_accept)
+            .map(|s| quote_state_name(&s.state))
+            .collect();
+        let symbol_list: Vec<String> = self
+            .alphabet
+            .iter()
+            .map(|s| quote_symbol(&s.to_string()))
+            .collect();
+
+        let mut text = format!(
+            "alphabet={{{}}}\nstate={{{}}}\nstart_state={}\nF={{{}}}\n",
+            symbol_list.join(","),
+            names.join(", "),
+            quote_state_name(self.start_name()),
+            accepting.join(", ")
+        );
+
+        for state in &states {
+            for (symbol, next) in self.alphabet.iter().zip(&state.transitions) {
+                if let Some(next) = next {
+                    text.push_str(&format!(
+                        "({}, {})->{}\n",
+                        quote_state_name(&state.state),
+                        quote_symbol(&symbol.to_string()),
+                        quote_state_name(&self.states[*next].state)
+                    ));
+                }
+            }
+        }
+
+        text
+    }
+
+    /**
+     Exporta la tabla de transiciones en formato CSV.
+     La primera fila lista los símbolos del alfabeto; cada fila siguiente empieza con el nombre del estado,
+     marcado con `->` si es el inicial y con `*` si es de aceptación, seguido del destino para cada símbolo
+     (vacío si no hay transición). Los estados se ordenan por nombre.
+        # Returns
+        Retorna un `String` con una línea por fila de la tabla.
+    */
+    pub fn to_csv(&self) -> String {
+        let mut header = vec![String::new()];
+        header.extend(
+            self.alphabet
+                .iter()
+                .map(|symbol| csv_field(&symbol.to_string())),
+        );
+        let mut csv = header.join(",") + "\n";
+
+        for (index, state) in self.sorted_state_indices() {
+            let mut name = String::new();
+            if index == self.start_state {
+                name.push_str("->");
+            }
+            if state.is_accept {
+                name.push('*');
+            }
+            name.push_str(&state.state);
+
+            let mut row = vec![csv_field(&name)];
+            row.extend(state.transitions.iter().map(|next| match next {
+                Some(next) => csv_field(&self.states[*next].state),
+                None => String::new(),
+            }));
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    // Retorna los estados junto a su índice, ordenados por nombre
+    fn sorted_state_indices(&self) -> Vec<(usize, &Node)> {
+        let mut states: Vec<(usize, &Node)> = self.states.iter().enumerate().collect();
+        states.sort_by(|(_, a), (_, b)| a.state.cmp(&b.state));
+        states
+    }
+
+    // Retorna los estados ordenados por nombre, para que la salida sea reproducible. El alfabeto no
+    // necesita algo equivalente porque siempre se guarda ordenado.
+    fn sorted_states(&self) -> Vec<&Node> {
+        self.sorted_state_indices()
+            .into_iter()
+            .map(|(_, state)| state)
+            .collect()
+    }
+
+    // Retorna el conjunto de estados con la forma `{q0, q1}`, ordenados por nombre
+    pub fn format_states(&self) -> String {
+        let states: Vec<&str> = self
+            .sorted_states()
+            .into_iter()
+            .map(|state| state.state.as_str())
+            .collect();
+        format!("{{{}}}", states.join(", "))
+    }
+
+    // Retorna el alfabeto con la forma `{'0', '1'}`
+    pub fn format_alphabet(&self) -> String {
+        let symbols: Vec<String> = self
+            .alphabet
+            .iter()
+            .map(|symbol| format!("{:?}", symbol))
+            .collect();
+        format!("{{{}}}", symbols.join(", "))
+    }
+
+    // Retorna el nombre del estado inicial
+    pub fn format_start_state(&self) -> String {
+        self.start_name().to_string()
+    }
+
+    // Retorna el conjunto de estados de aceptación con la forma `{q1, q2}`, ordenados por nombre
+    pub fn format_accept_states(&self) -> String {
+        let accepting: Vec<&str> = self
+            .sorted_states()
+            .into_iter()
+            .filter(|state| state.is_accept)
+            .map(|state| state.state.as_str())
+            .collect();
+        format!("{{{}}}", accepting.join(", "))
+    }
+
+    // Retorna las transiciones del autómata, una línea `δ(q, a) = p` por transición
+    pub fn format_transitions(&self) -> String {
+        let mut text = String::new();
+        for state in self.sorted_states() {
+            for (symbol, next_state) in self.alphabet.iter().zip(&state.transitions) {
+                if let Some(next_state) = next_state {
+                    text.push_str(&format!(
+                        "δ({}, {}) = {}\n",
+                        state.state, symbol, self.states[*next_state].state
+                    ));
+                }
+            }
+        }
+        text
+    }
+
+    // Retorna la 5-tupla (definición formal del DFA)
+    pub fn format_tupla(&self) -> String {
+        self.to_string()
+    }
+
+    // Retorna la 5-tupla seguida de una línea que indica si la función de transición es total, por
+    // ejemplo `(parcial: faltan 2 transiciones)`
+    pub fn format_tupla_with_diagnostics(&self) -> String {
+        let missing = self.state_count() * self.alphabet_size() - self.transition_count();
+        let note = match missing {
+            0 => "(completo)".to_string(),
+            1 => "(parcial: falta 1 transición)".to_string(),
+            _ => format!("(parcial: faltan {} transiciones)", missing),
+        };
+        format!("{}\n{}", self, note)
+    }
+}
+
+impl<S: Symbol> Default for NFA<S> {
+    fn default() -> Self {
+        NFA::new()
+    }
+}
+
+impl<S: Symbol> NFA<S> {
+    pub fn new() -> NFA<S> {
+        NFA {
+            states: Vec::new(),
+            alphabet: HashSet::new(),
+            start_states: HashSet::new(),
+            accept_states: HashSet::new(),
+            transitions: HashMap::new(),
+            epsilon_transitions: HashMap::new(),
+        }
+    }
+
+    // Agrega un estado al autómata; si ya existe solo se actualiza su aceptación
+    pub fn add_state(&mut self, name: &str, is_accept: bool) -> &mut NFA<S> {
+        if !self.states.iter().any(|state| state == name) {
+            self.states.push(name.to_string());
+        }
+        if is_accept {
+            self.accept_states.insert(name.to_string());
+        } else {
+            self.accept_states.remove(name);
+        }
+        self
+    }
+
+    // Marca un estado como inicial; un NFA puede tener varios estados iniciales
+    pub fn add_start_state(&mut self, name: &str) -> &mut NFA<S> {
+        self.start_states.insert(name.to_string());
+        self
+    }
+
+    // Agrega una transición; su símbolo se incorpora al alfabeto
+    pub fn add_transition(&mut self, from: &str, symbol: S, to: &str) -> &mut NFA<S> {
+        self.alphabet.insert(symbol.clone());
+        self.transitions
+            .entry(from.to_string())
+            .or_default()
+            .entry(symbol)
+            .or_default()
+            .insert(to.to_string());
+        self
+    }
+
+    // Agrega una transición épsilon, que cambia de estado sin consumir símbolos
+    pub fn add_epsilon_transition(&mut self, from: &str, to: &str) -> &mut NFA<S> {
+        self.epsilon_transitions
+            .entry(from.to_string())
+            .or_default()
+            .insert(to.to_string());
+        self
+    }
+
+    /**
+     Calcula la clausura épsilon de un conjunto de estados.
+        # Arguments
+        * `states` - Los estados de partida.
+        # Returns
+        Retorna un `HashSet<String>` con los estados de partida y todos los alcanzables usando solo transiciones épsilon.
+    */
+    fn epsilon_closure(&self, states: &HashSet<String>) -> HashSet<String> {
+        let mut closure = states.clone();
+        let mut pending: Vec<String> = states.iter().cloned().collect();
+
+        while let Some(state) = pending.pop() {
+            if let Some(targets) = self.epsilon_transitions.get(&state) {
+                for target in targets {
+                    if closure.insert(target.clone()) {
+                        pending.push(target.clone());
+                    }
+                }
+            }
+        }
+
+        closure
+    }
+
+    // Retorna la clausura épsilon de los estados a los que se llega desde `states` leyendo `symbol`
+    fn step(&self, states: &HashSet<String>, symbol: &S) -> HashSet<String> {
+        let targets = states
+            .iter()
+            .filter_map(|state| self.transitions.get(state)?.get(symbol))
+            .flatten()
+            .cloned()
+            .collect();
+        self.epsilon_closure(&targets)
+    }
+
+    /**
+     Valida si la secuencia de símbolos es aceptada, siguiendo todos los caminos posibles a la vez.
+        # Arguments
+        * `input` - Los símbolos de la palabra a analizar.
+        # Returns
+        Retorna `true` si algún camino termina en un estado de aceptación.
+    */
+    pub fn accepts(&self, input: &[S]) -> bool {
+        let mut current = self.epsilon_closure(&self.start_states);
+        for symbol in input {
+            current = self.step(&current, symbol);
+            if current.is_empty() {
+                return false;
+            }
+        }
+        current
+            .iter()
+            .any(|state| self.accept_states.contains(state))
+    }
+
+    // Nombra un conjunto de estados con sus miembros ordenados y separados por comas
+    fn subset_name(states: &HashSet<String>) -> String {
+        if states.is_empty() {
+            return "∅".to_string();
+        }
+        let mut names: Vec<&str> = states.iter().map(|state| state.as_str()).collect();
+        names.sort();
+        names.join(",")
+    }
+
+    /**
+     Construye un autómata determinista equivalente mediante la construcción de subconjuntos.
+     Cada subconjunto se cierra bajo las transiciones épsilon. Solo se generan los subconjuntos
+     alcanzables desde el conjunto inicial; las transiciones que llevarían al conjunto vacío se omiten,
+     por lo que el resultado puede ser parcial.
+        # Returns
+        Retorna un `DFA` cuyos estados se nombran con los estados del NFA que representan, por ejemplo `q0,q1`.
+    */
+    pub fn to_dfa(&self) -> DFA<S> {
+        let mut dfa = DFA::with_alphabet(self.alphabet.iter().cloned());
+
+        let add_subset = |dfa: &mut DFA<S>, subset: &HashSet<String>| {
+            dfa.push_state(
+                &NFA::<S>::subset_name(subset),
+                subset
+                    .iter()
+                    .any(|state| self.accept_states.contains(state)),
+            )
+        };
+
+        let start_subset = self.epsilon_closure(&self.start_states);
+        dfa.start_state = add_subset(&mut dfa, &start_subset);
+        let mut subsets: HashMap<String, usize> = HashMap::new();
+        subsets.insert(NFA::<S>::subset_name(&start_subset), dfa.start_state);
+
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back((start_subset, dfa.start_state));
+
+        while let Some((subset, node)) = queue.pop_front() {
+            for symbol in 0..dfa.alphabet.len() {
+                let next_subset = self.step(&subset, &dfa.alphabet[symbol]);
+                if next_subset.is_empty() {
+                    continue;
+                }
+
+                let next = match subsets.get(&NFA::<S>::subset_name(&next_subset)) {
+                    Some(&next) => next,
+                    None => {
+                        let next = add_subset(&mut dfa, &next_subset);
+                        subsets.insert(NFA::<S>::subset_name(&next_subset), next);
+                        queue.push_back((next_subset, next));
+                        next
+                    }
+                };
+                dfa.states[node].transitions[symbol] = Some(next);
+            }
+        }
+
+        dfa
+    }
+}
+
+impl NFA {
+    /**
+     Construye un NFA con transiciones épsilon a partir de una expresión regular (construcción de Thompson).
+        # Arguments
+        * `pattern` - La expresión regular.
+        # Returns
+        Retorna un NFA con un único estado inicial y un único estado de aceptación, o un `RegexError`
+        si la expresión está mal formada.
+    */
+    pub fn from_regex(pattern: &str) -> Result<NFA, RegexError> {
+        let regex = RegexParser::parse(pattern)?;
+        let mut nfa = NFA::new();
+        let (start, accept) = nfa.add_regex(&regex);
+        nfa.add_start_state(&start);
+        nfa.add_state(&accept, true);
+        Ok(nfa)
+    }
+
+    // Agrega un estado nuevo con un nombre que todavía no se ha usado
+    fn fresh_state(&mut self) -> String {
+        let name = format!("q{}", self.states.len());
+        self.add_state(&name, false);
+        name
+    }
+
+    // Agrega el fragmento de Thompson de `regex` y retorna sus estados de entrada y de salida
+    fn add_regex(&mut self, regex: &Regex) -> (String, String) {
+        match regex {
+            Regex::Nothing => (self.fresh_state(), self.fresh_state()),
+            Regex::Empty => {
+                let start = self.fresh_state();
+                let end = self.fresh_state();
+                self.add_epsilon_transition(&start, &end);
+                (start, end)
+            }
+            Regex::Symbol(symbol) => {
+                let start = self.fresh_state();
+                let end = self.fresh_state();
+                self.add_transition(&start, *symbol, &end);
+                (start, end)
+            }
+            Regex::Concat(left, right) => {
+                let (left_start, left_end) = self.add_regex(left);
+                let (right_start, right_end) = self.add_regex(right);
+                self.add_epsilon_transition(&left_end, &right_start);
+                (left_start, right_end)
+            }
+            Regex::Union(left, right) => {
+                let start = self.fresh_state();
+                let (left_start, left_end) = self.add_regex(left);
+                let (right_start, right_end) = self.add_regex(right);
+                let end = self.fresh_state();
+                self.add_epsilon_transition(&start, &left_start)
+                    .add_epsilon_transition(&start, &right_start)
+                    .add_epsilon_transition(&left_end, &end)
+                    .add_epsilon_transition(&right_end, &end);
+                (start, end)
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => {
+                let start = self.fresh_state();
+                let (inner_start, inner_end) = self.add_regex(inner);
+                let end = self.fresh_state();
+                self.add_epsilon_transition(&start, &inner_start)
+                    .add_epsilon_transition(&inner_end, &end);
+                // `*` y `?` aceptan la palabra vacía; `*` y `+` permiten repetir
+                if !matches!(regex, Regex::Plus(_)) {
+                    self.add_epsilon_transition(&start, &end);
+                }
+                if !matches!(regex, Regex::Optional(_)) {
+                    self.add_epsilon_transition(&inner_end, &inner_start);
+                }
+                (start, end)
+            }
+        }
+    }
+}
+
+impl MealyMachine {
+    /**
+     Lee una máquina de Mealy con el mismo formato de texto que `DFA::from_string`, donde cada
+     transición indica además su salida: `(q0, a)->q1 / x`. La línea `F=` es opcional.
+        # Arguments
+        * `description` - La descripción de la máquina.
+        # Returns
+        Retorna la máquina o un `DfaParseError` si alguna línea está mal formada o una transición no
+        tiene salida.
+    */
+    pub fn from_string(description: &str) -> Result<MealyMachine, DfaParseError> {
+        // Las transiciones se quitan de la descripción, sin cambiar la numeración de las líneas, y se
+        // agregan después junto con su salida
+        let mut lines = Vec::new();
+        let mut transitions = Vec::new();
+        for (index, line) in description.lines().enumerate() {
+            let trimmed = line.trim();
+            if !trimmed.starts_with('(') {
+                lines.push(line);
+                continue;
+            }
+            lines.push("");
+
+            let arrow = trimmed
+                .find("->")
+                .ok_or_else(|| DfaParseError::MissingArrow {
+                    line: index + 1,
+                    text: trimmed.to_string(),
+                })?
+                + 2;
+            let (target, output) =
+                trimmed[arrow..]
+                    .split_once('/')
+                    .ok_or_else(|| DfaParseError::MissingOutput {
+                        line: index + 1,
+                        text: trimmed.to_string(),
+                    })?;
+            let transition = format!("{}{}", &trimmed[..arrow], target.trim());
+            transitions.push((index + 1, transition, output.trim().to_string()));
+        }
+
+        let mut automaton = DFA::from_string(&lines.join("\n"))?;
+        let mut outputs = vec![vec![None; automaton.alphabet.len()]; automaton.states.len()];
+        for (line, transition, output) in transitions {
+            let (state, symbol) =
+                automaton.add_transition_line(&transition, line, read_char_symbol)?;
+            outputs[state][symbol] = Some(output);
+        }
+
+        Ok(MealyMachine { automaton, outputs })
+    }
+
+    /**
+     Traduce una palabra concatenando las salidas de las transiciones que recorre.
+        # Arguments
+        * `input` - La palabra a traducir.
+        # Returns
+        Retorna la traducción, o `None` si falta alguna transición o un símbolo no pertenece al alfabeto.
+    */
+    pub fn transduce(&self, input: &str) -> Option<String> {
+        let mut state = self.automaton.start_state;
+        let mut output = String::new();
+        for symbol in input.chars() {
+            let index = self.automaton.symbol_index(&symbol)?;
+            output.push_str(self.outputs[state][index].as_deref()?);
+            state = self.automaton.states[state].transitions[index]?;
+        }
+        Some(output)
+    }
+}
+
+impl Iterator for LanguageIter<'_> {
+    type Item = String;
+
+    // Recorre en anchura los prefijos; solo se exploran los que pueden completarse a una palabra aceptada,
+    // así que cada llamada termina y el iterador se agota si el lenguaje es finito
+    fn next(&mut self) -> Option<String> {
+        while let Some((state, prefix)) = self.frontier.pop_front() {
+            let node = &self.dfa.states[state];
+            for (symbol, next) in self.dfa.alphabet.iter().zip(&node.transitions) {
+                if let Some(next) = *next {
+                    if self.live[next] {
+                        let mut word = prefix.clone();
+                        word.push(*symbol);
+                        self.frontier.push_back((next, word));
+                    }
+                }
+            }
+            if node.is_accept {
+                return Some(prefix);
+            }
+        }
+        None
+    }
+}
+
+// Escapa los caracteres especiales de LaTeX
+fn latex_escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Escribe un campo CSV, entre comillas si contiene comas, comillas o saltos de línea
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+// Separa una fila CSV en sus campos, respetando las comillas, y quita los espacios alrededor de cada uno
+fn read_csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // Dos comillas seguidas dentro de un campo entre comillas representan una comilla
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+// Lee una lista de estados con la forma `{q0, q1}`, quitando los espacios alrededor de cada nombre.
+// Un nombre entre comillas puede contener comas, como `{"p,e", q1}`
+fn read_state_names(names: &str) -> Vec<String> {
+    let names = names.trim();
+    let names = names.strip_prefix('{').unwrap_or(names);
+    let names = names.strip_suffix('}').unwrap_or(names);
+
+    let mut list = Vec::new();
+    let mut rest = names;
+    loop {
+        let (name, next) = match find_unquoted_comma(rest) {
+            Some(comma) => (&rest[..comma], Some(&rest[comma + 1..])),
+            None => (rest, None),
+        };
+        let name = name.trim();
+        if !name.is_empty() {
+            list.push(unquote_state_name(name));
+        }
+        match next {
+            Some(next) => rest = next,
+            None => return list,
+        }
+    }
+}
+
+// Retorna la posición de la primera coma que no está entre comillas
+fn find_unquoted_comma(text: &str) -> Option<usize> {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (position, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => return Some(position),
+            _ => {}
+        }
+    }
+    None
+}
+
+// Quita el comentario al final de una línea: un `#` fuera de comillas, precedido por un espacio y seguido
+// por otro espacio o por el fin de la línea. Así `(q0, #)->q1` y `alphabet={a, #}` conservan el símbolo
+fn strip_trailing_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut previous = ' ';
+    for (position, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted && previous.is_whitespace() => {
+                let rest = &line[position + 1..];
+                if rest.chars().next().is_none_or(char::is_whitespace) {
+                    return &line[..position];
+                }
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    line
+}
+
+// Escribe el nombre de un estado para el formato de texto. Se pone entre comillas, escapando `"` y `\`,
+// si está vacío, tiene espacios en los extremos o contiene caracteres que el lector interpretaría
+fn quote_state_name(name: &str) -> String {
+    let plain = !name.is_empty()
+        && name.trim() == name
+        && !name.contains(['"', ',', '{', '}', '(', ')'])
+        && !name.contains("->");
+    if plain {
+        return name.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    for c in name.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+// Escribe un símbolo para el formato de texto. Los tokens que contienen comas, llaves, paréntesis o
+// espacios se ponen entre comillas, como los acepta `read_token_symbol`. Un `(` o `)` solo se deja tal
+// cual porque se lee bien sin comillas y los alfabetos de caracteres no admiten comillas
+fn quote_symbol(symbol: &str) -> String {
+    let lone_paren = symbol == "(" || symbol == ")";
+    let special =
+        symbol.contains([',', '{', '}', '(', ')']) || symbol.contains(char::is_whitespace);
+    if special && !lone_paren {
+        format!("\"{}\"", symbol)
+    } else {
+        symbol.to_string()
+    }
+}
+
+// Lee un nombre escrito con `quote_state_name`; un nombre sin comillas se retorna tal cual
+fn unquote_state_name(name: &str) -> String {
+    let inner = match name
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        Some(inner) => inner,
+        None => return name.to_string(),
+    };
+
+    let mut unquoted = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            _ => unquoted.push(c),
+        }
+    }
+    unquoted
+}
+
+// Retorna la posición de la comilla que abre el nombre entre comillas con el que termina `text`
+fn opening_quote(text: &str) -> Option<usize> {
+    let body = text.strip_suffix('"')?;
+    // Una comilla escapada está precedida por una cantidad impar de `\`
+    body.rmatch_indices('"')
+        .map(|(position, _)| position)
+        .find(|&position| {
+            body[..position]
+                .chars()
+                .rev()
+                .take_while(|&c| c == '\\')
+                .count()
+                % 2
+                == 0
+        })
+}
+
+// Lee un alfabeto de caracteres: cada carácter distinto de coma o espacio es un símbolo
+fn read_char_alphabet(symbols: &str) -> Vec<char> {
+    symbols.chars().filter(|&c| c != ',' && c != ' ').collect()
+}
+
+// Separa una línea `(estado, símbolo)->estado` en sus tres partes, sin espacios alrededor. Los nombres
+// de los estados pueden estar entre comillas, como `("p,e", 0)->"p,o"`
+fn split_transition_line<S>(
+    input: &str,
+    line: usize,
+) -> Result<(String, &str, String), DfaParseError<S>> {
+    let malformed = || DfaParseError::MalformedTransition {
+        line,
+        text: input.to_string(),
+    };
+    let input = input.trim();
+
+    // El destino es el nombre entre comillas con el que termina la línea o lo que sigue a la última flecha
+    let (transition_part, next_state_name) = match opening_quote(input) {
+        Some(quote) => {
+            let transition_part = input[..quote]
+                .trim_end()
+                .strip_suffix("->")
+                .ok_or_else(malformed)?;
+            (transition_part, &input[quote..])
+        }
+        None => input
+            .rsplit_once("->")
+            .ok_or_else(|| DfaParseError::MissingArrow {
+                line,
+                text: input.to_string(),
+            })?,
+    };
+    let transition_part = transition_part.trim();
+    let next_state_name = next_state_name.trim();
+
+    // Remover los paréntesis y separar el estado del símbolo en la primera coma fuera de comillas
+    let transition_inner = transition_part
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(malformed)?;
+    let comma = find_unquoted_comma(transition_inner).ok_or_else(malformed)?;
+    let (state_input, symbol_input) = (&transition_inner[..comma], &transition_inner[comma + 1..]);
+
+    if next_state_name.is_empty() {
+        return Err(malformed());
+    }
+
+    Ok((
+        unquote_state_name(state_input.trim()),
+        symbol_input.trim(),
+        unquote_state_name(next_state_name),
+    ))
+}
+
+// Lee el símbolo de una transición, que debe ser exactamente un carácter (puede no ser ASCII, como `α`)
+fn read_char_symbol(symbol: &str) -> Option<char> {
+    let mut chars = symbol.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// Lee un alfabeto de bytes separados por comas; los que no son un byte válido se ignoran
+fn read_byte_alphabet(symbols: &str) -> Vec<u8> {
+    symbols
+        .split(',')
+        .filter_map(|symbol| read_byte_symbol(symbol.trim()))
+        .collect()
+}
+
+// Lee un byte escrito como un carácter ASCII o con la forma `\xNN`
+fn read_byte_symbol(symbol: &str) -> Option<u8> {
+    match symbol.strip_prefix("\\x") {
+        Some(hex) if hex.len() == 2 => u8::from_str_radix(hex, 16).ok(),
+        _ => read_char_symbol(symbol)
+            .filter(char::is_ascii)
+            .map(|c| c as u8),
+    }
+}
+
+// Lee un alfabeto de tokens separados por comas; un token entre comillas puede contener comas o espacios
+fn read_token_alphabet(symbols: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in symbols.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.push(c);
+            }
+            ',' if !quoted => tokens.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    tokens.push(current);
+
+    tokens
+        .iter()
+        .filter_map(|token| read_token_symbol(token.trim()))
+        .collect()
+}
+
+// Lee un token, quitando las comillas si las tiene; un token sin comillas no puede contener comas
+fn read_token_symbol(symbol: &str) -> Option<String> {
+    if symbol.len() >= 2 && symbol.starts_with('"') && symbol.ends_with('"') {
+        return Some(symbol[1..symbol.len() - 1].to_string());
+    }
+    if symbol.is_empty() || symbol.contains(',') {
+        return None;
+    }
+    Some(symbol.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Palabras sobre {0,1} que terminan en 1
+    fn ends_in_one() -> DFA {
+        DFA::from_string(
+            "alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,0)->a\n(a,1)->b\n(b,0)->a\n(b,1)->b",
+        )
+        .unwrap()
+    }
+
+    // Palabras sobre {0,1} de longitud par
+    fn even_length() -> DFA {
+        DFA::from_string(
+            "alphabet={0,1}\nstate={e,o}\nstart_state=e\nF={e}\n(e,0)->o\n(e,1)->o\n(o,0)->e\n(o,1)->e",
+        )
+        .unwrap()
+    }
+
+    // Autómata de ejemplo del programa: acepta las palabras que contienen "10"
+    fn sample() -> DFA {
+        DFA::from_string(
+            "alphabet={0,1}
+            state={q0, q1, q1q2, q2}
+            start_state=q0
+            F={q1q2, q2}
+            (q0, 1)->q1
+            (q0, 0)->q0
+            (q1, 1)->q1
+            (q1, 0)->q1q2
+            (q1q2, 0)->q1q2
+            (q1q2, 1)->q1q2
+            (q2, 0)->q2
+            (q2, 1)->q1q2",
+        )
+        .unwrap()
+    }
+
+    // Todas las palabras sobre `alphabet` de longitud menor o igual a `max_len`
+    fn words(alphabet: &[char], max_len: usize) -> Vec<String> {
+        let mut all = vec![String::new()];
+        let mut level = vec![String::new()];
+        for _ in 0..max_len {
+            level = level
+                .iter()
+                .flat_map(|word| {
+                    alphabet.iter().map(move |&symbol| {
+                        let mut next = word.clone();
+                        next.push(symbol);
+                        next
+                    })
+                })
+                .collect();
+            all.extend(level.iter().cloned());
+        }
+        all
+    }
+
+    #[test]
+    fn conflicting_transitions_are_an_error() {
+        let result = DFA::from_string(
+            "alphabet={0,1}\nstate={q0, q1, q2}\nstart_state=q0\nF={q2}\n(q0,1)->q1\n(q0,1)->q2",
+        );
+        assert!(matches!(
+            result,
+            Err(DfaParseError::DuplicateTransition { line: 6, ref state, symbol: '1' }) if state == "q0"
+        ));
+
+        // Repetir exactamente la misma transición también es un error
+        let result =
+            DFA::from_string("alphabet={0}\nstate={q0}\nstart_state=q0\n(q0,0)->q0\n(q0,0)->q0");
+        assert!(matches!(
+            result,
+            Err(DfaParseError::DuplicateTransition { line: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn minimize_merges_equivalent_states() {
+        let dfa = sample();
+        let minimal = dfa.minimize();
+        // q1q2 y q2 aceptan el mismo lenguaje
+        assert_eq!(minimal.states.len(), 3);
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(dfa.run(&word), minimal.run(&word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn minimize_keeps_partial_automata_partial() {
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,c,u}\nstart_state=a\nF={c}\n(a,0)->b\n(b,0)->c\n(c,0)->c\n(u,0)->a",
+        )
+        .unwrap();
+        let minimal = dfa.minimize();
+        // Se descarta el estado inalcanzable y no queda un estado trampa
+        assert_eq!(minimal.states.len(), 3);
+        assert!(!minimal.is_complete());
+        for word in words(&['0', '1'], 5) {
+            assert_eq!(dfa.run(&word), minimal.run(&word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn complement_flips_every_word() {
+        let dfa = sample();
+        let complement = dfa.complement();
+        for word in words(&['0', '1'], 6) {
+            assert_ne!(dfa.run(&word), complement.run(&word), "{}", word);
+        }
+
+        // Un autómata parcial se completa antes de invertir los estados de aceptación
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b")
+                .unwrap();
+        let complement = partial.complement();
+        assert!(complement.run("0") && complement.run("11") && !complement.run("1"));
+    }
+
+    #[test]
+    fn intersection_accepts_words_of_both() {
+        let (a, b) = (ends_in_one(), even_length());
+        let intersection = a.intersection(&b);
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(
+                intersection.run(&word),
+                a.run(&word) && b.run(&word),
+                "{}",
+                word
+            );
+        }
+        assert!(intersection.run("01"));
+        assert!(!intersection.run("1"));
+        assert_eq!(intersection.start_name(), "a,e");
+    }
+
+    #[test]
+    fn union_accepts_words_of_either() {
+        let (a, b) = (ends_in_one(), even_length());
+        let union = a.union(&b);
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(union.run(&word), a.run(&word) || b.run(&word), "{}", word);
+        }
+        assert!(union.run("") && union.run("1") && !union.run("0"));
+    }
+
+    #[test]
+    fn difference_removes_words_of_the_second() {
+        let (a, b) = (ends_in_one(), even_length());
+        let difference = a.difference(&b);
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(
+                difference.run(&word),
+                a.run(&word) && !b.run(&word),
+                "{}",
+                word
+            );
+        }
+        assert!(difference.run("1") && !difference.run("01"));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn is_empty_detects_unreachable_accepting_states() {
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,0)->a\n(b,1)->b",
+        )
+        .unwrap();
+        assert!(dfa.is_empty());
+        assert!(!sample().is_empty());
+        assert!(ends_in_one()
+            .intersection(&ends_in_one().complement())
+            .is_empty());
+    }
+
+    #[test]
+    fn equivalent_compares_languages() {
+        let dfa = sample();
+        assert!(dfa.equivalent(&dfa.minimize()));
+        assert!(!dfa.equivalent(&ends_in_one()));
+
+        // Longitud par con un estado redundante
+        let redundant = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,c}\nstart_state=a\nF={a,c}\n(a,0)->b\n(a,1)->b\n(b,0)->c\n(b,1)->c\n(c,0)->b\n(c,1)->b",
+        )
+        .unwrap();
+        assert!(redundant.equivalent(&even_length()));
+        assert!(!redundant.equivalent(&even_length().complement()));
+    }
+
+    #[test]
+    fn to_dot_declares_nodes_and_edges() {
+        let dot = sample().to_dot();
+        assert!(dot.starts_with("digraph DFA {\n"));
+        assert!(dot.contains("    \"q0\" [shape=circle];\n"));
+        assert!(dot.contains("    \"q2\" [shape=doublecircle];\n"));
+        assert!(dot.contains("    __start -> \"q0\";\n"));
+        assert!(dot.contains("    \"q0\" -> \"q1\" [label=\"1\"];\n"));
+        // Los símbolos entre el mismo par de estados se agrupan en una sola arista
+        assert!(dot.contains("    \"q1q2\" -> \"q1q2\" [label=\"0,1\"];\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn to_string_format_round_trips() {
+        let dfa = sample();
+        let text = dfa.to_string_format();
+        let parsed = DFA::from_string(&text).unwrap();
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(dfa.run(&word), parsed.run(&word), "{}", word);
+        }
+        assert_eq!(text, parsed.to_string_format());
+    }
+
+    #[test]
+    fn to_string_format_round_trips_product_names() {
+        // Los estados del producto se llaman como `a,e`
+        let intersection = ends_in_one().intersection(&even_length());
+        let text = intersection.to_string_format();
+        assert!(text.contains("start_state=\"a,e\"\n"));
+        let parsed = DFA::from_string(&text).unwrap();
+        assert_eq!(parsed.start_name(), "a,e");
+        assert!(parsed.equivalent(&intersection));
+        assert_eq!(text, parsed.to_string_format());
+
+        // Lo mismo con los estados de la construcción de subconjuntos
+        let mut nfa: NFA = NFA::new();
+        nfa.add_state("a", false)
+            .add_state("b", true)
+            .add_start_state("a");
+        nfa.add_transition("a", '0', "a")
+            .add_transition("a", '0', "b");
+        let dfa = nfa.to_dfa();
+        let parsed = DFA::from_string(&dfa.to_string_format()).unwrap();
+        assert!(parsed.equivalent(&dfa));
+    }
+
+    #[test]
+    fn to_string_format_quotes_special_names() {
+        let mut dfa = sample();
+        for (old, new) in [
+            ("q0", "(q->0)"),
+            ("q1", "\"uno\" \\ {1}"),
+            ("q1q2", " espacios "),
+            ("q2", ""),
+        ] {
+            dfa.rename_state(old, new).unwrap();
+        }
+        let text = dfa.to_string_format();
+        let parsed = DFA::from_string(&text).unwrap();
+        let mut names: Vec<&str> = dfa.states().collect();
+        names.sort();
+        assert_eq!(parsed.states().collect::<Vec<_>>(), names);
+        assert_eq!(parsed.start_name(), "(q->0)");
+        assert!(parsed.equivalent(&dfa));
+        assert_eq!(text, parsed.to_string_format());
+    }
+
+    #[test]
+    fn dfa_data_round_trips() {
+        let dfa = sample();
+        let data = DfaData::from(&dfa);
+        assert_eq!(data.start_state, "q0");
+        assert_eq!(data.transitions.len(), 8);
+        let rebuilt = DFA::from_data(data.clone()).unwrap();
+        assert_eq!(DfaData::from(&rebuilt), data);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn dfa_data_round_trips_through_json() {
+        let dfa = sample();
+        let json = serde_json::to_string(&DfaData::from(&dfa)).unwrap();
+        assert!(json.contains("{\"from\":\"q0\",\"symbol\":\"1\",\"to\":\"q1\"}"));
+        let data: DfaData = serde_json::from_str(&json).unwrap();
+        let rebuilt = DFA::from_data(data).unwrap();
+        assert_eq!(rebuilt.to_string_format(), dfa.to_string_format());
+    }
+
+    #[test]
+    fn run_trace_records_visited_states() {
+        let trace = sample().run_trace("0110");
+        assert!(trace.accepted());
+        assert!(!trace.halted());
+        assert_eq!(trace.states, ["q0", "q0", "q1", "q1", "q1q2"]);
+        assert_eq!(trace.symbols, ['0', '1', '1', '0']);
+        assert_eq!(
+            trace.to_string(),
+            "q0 --0--> q0 --1--> q1 --1--> q1 --0--> q1q2"
+        );
+
+        // La ejecución se detiene en el primer símbolo sin transición
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,0)->b")
+                .unwrap();
+        let trace = partial.run_trace("01");
+        assert!(trace.halted() && !trace.accepted());
+        assert_eq!(trace.states, ["a", "b"]);
+        assert_eq!(trace.halted_on, Some('1'));
+    }
+
+    #[test]
+    fn read_only_views() {
+        let dfa = sample();
+        assert_eq!(dfa.states().collect::<Vec<_>>(), ["q0", "q1", "q1q2", "q2"]);
+        assert_eq!(dfa.is_accepting_state("q2"), Some(true));
+        assert_eq!(dfa.is_accepting_state("q0"), Some(false));
+        assert_eq!(dfa.is_accepting_state("q9"), None);
+        assert_eq!(dfa.transition("q1", '0').as_deref(), Some("q1q2"));
+        assert_eq!(dfa.transition("q1", '2'), None);
+        assert_eq!(dfa.transition("q9", '0'), None);
+    }
+
+    #[test]
+    fn run_many_classifies_each_word() {
+        let results = sample().run_many(["0110", "", "1", "10", "0x", "111000"]);
+        assert_eq!(results, [true, false, false, true, false, true]);
+    }
+
+    #[test]
+    fn accepted_words_in_shortlex_order() {
+        assert_eq!(
+            sample().accepted_words(3),
+            ["10", "010", "100", "101", "110"]
+        );
+        // La palabra vacía se incluye si el estado inicial es de aceptación
+        assert_eq!(
+            even_length().accepted_words(2),
+            ["", "00", "01", "10", "11"]
+        );
+        assert!(sample().accepted_words(1).is_empty());
+    }
+
+    #[test]
+    fn count_accepted_matches_enumeration() {
+        let dfa = sample();
+        for length in 0..8 {
+            let enumerated = dfa
+                .accepted_words(length)
+                .iter()
+                .filter(|word| word.len() == length)
+                .count();
+            assert_eq!(dfa.count_accepted(length), enumerated as u64, "{}", length);
+        }
+        // Sin enumerar: todas las palabras de longitud 40 tienen longitud par
+        assert_eq!(even_length().count_accepted(40), 1 << 40);
+    }
+
+    #[test]
+    fn shortest_accepted_word_is_a_minimal_witness() {
+        assert_eq!(sample().shortest_accepted_word().as_deref(), Some("10"));
+        assert_eq!(even_length().shortest_accepted_word().as_deref(), Some(""));
+        assert_eq!(
+            ends_in_one()
+                .intersection(&ends_in_one().complement())
+                .shortest_accepted_word(),
+            None
+        );
+    }
+
+    #[test]
+    fn nfa_subset_construction() {
+        // Palabras que contienen "01"
+        let mut nfa: NFA = NFA::new();
+        nfa.add_state("a", false)
+            .add_state("b", false)
+            .add_state("c", true)
+            .add_start_state("a");
+        nfa.add_transition("a", '0', "a")
+            .add_transition("a", '1', "a")
+            .add_transition("a", '0', "b")
+            .add_transition("b", '1', "c")
+            .add_transition("c", '0', "c")
+            .add_transition("c", '1', "c");
+
+        let dfa = nfa.to_dfa();
+        for word in words(&['0', '1'], 7) {
+            let symbols: Vec<char> = word.chars().collect();
+            assert_eq!(nfa.accepts(&symbols), word.contains("01"), "{}", word);
+            assert_eq!(dfa.run(&word), word.contains("01"), "{}", word);
+        }
+        // Solo se crean los subconjuntos alcanzables
+        assert!(dfa.state_count() < 8);
+    }
+
+    #[test]
+    fn nfa_epsilon_closure() {
+        let mut nfa: NFA = NFA::new();
+        nfa.add_state("s", false)
+            .add_state("m", false)
+            .add_state("f", true)
+            .add_start_state("s");
+        nfa.add_epsilon_transition("s", "m")
+            .add_epsilon_transition("m", "f")
+            .add_transition("f", 'a', "s");
+
+        assert!(nfa.accepts(&[]));
+        let dfa = nfa.to_dfa();
+        assert!(dfa.run(""));
+        assert!(dfa.run("aaa"));
+        assert!(!dfa.run("b"));
+    }
+
+    #[test]
+    fn from_regex_builds_minimal_dfa() {
+        let dfa = DFA::from_regex("(0|1)*1").unwrap();
+        for word in words(&['0', '1'], 7) {
+            assert_eq!(dfa.run(&word), word.ends_with('1'), "{}", word);
+        }
+        assert_eq!(dfa.state_count(), 2);
+
+        let dfa = DFA::from_regex("a+b?").unwrap();
+        assert!(dfa.run("a") && dfa.run("aab"));
+        assert!(!dfa.run("") && !dfa.run("b") && !dfa.run("abb"));
+        assert!(DFA::from_regex("a\\*").unwrap().run("a*"));
+        assert!(DFA::from_regex("").unwrap().run(""));
+    }
+
+    #[test]
+    fn from_regex_rejects_invalid_patterns() {
+        for pattern in ["(01", "01)", "*1", "a|*"] {
+            assert!(DFA::from_regex(pattern).is_err(), "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn reverse_accepts_reversed_words() {
+        // Palabras que empiezan con 0
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,0)->b\n(b,0)->b\n(b,1)->b",
+        )
+        .unwrap();
+        let reversed = dfa.reverse();
+        for word in words(&['0', '1'], 6) {
+            let symbols: Vec<char> = word.chars().rev().collect();
+            assert_eq!(reversed.accepts(&symbols), dfa.run(&word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn minimize_brzozowski_matches_hopcroft() {
+        let dfa = sample();
+        let minimal = dfa.minimize_brzozowski();
+        assert_eq!(minimal.state_count(), dfa.minimize().state_count());
+        assert!(minimal.equivalent(&dfa));
+    }
+
+    #[test]
+    fn to_regex_round_trips() {
+        let empty = DFA::from_string("alphabet={0}\nstate={a}\nstart_state=a\nF={}").unwrap();
+        for dfa in [
+            sample(),
+            ends_in_one(),
+            even_length(),
+            DFA::from_regex("a(b|c)*\\*").unwrap(),
+            empty,
+        ] {
+            let regex = dfa.to_regex();
+            let parsed = DFA::from_regex(&regex).unwrap();
+            for word in words(&dfa.alphabet, 5) {
+                assert_eq!(dfa.run(&word), parsed.run(&word), "{} {}", regex, word);
+            }
+        }
+    }
+
+    #[test]
+    fn dead_states_of_partial_dfa() {
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,t}\nstart_state=a\nF={b}\n(a,0)->b\n(a,1)->t\n(t,0)->t",
+        )
+        .unwrap();
+        assert_eq!(dfa.dead_states(), HashSet::from(["t".to_string()]));
+        assert!(sample().dead_states().is_empty());
+    }
+
+    #[test]
+    fn is_finite_ignores_cycles_outside_live_states() {
+        assert!(!DFA::from_regex("ab*").unwrap().is_finite());
+        assert!(DFA::from_regex("ab|ba").unwrap().is_finite());
+        assert!(DFA::from_regex("∅").unwrap().is_finite());
+        // El único ciclo está en un estado muerto
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,t}\nstart_state=a\nF={b}\n(a,0)->b\n(a,1)->t\n(t,0)->t",
+        )
+        .unwrap();
+        assert!(dfa.is_finite());
+    }
+
+    #[test]
+    fn run_reader_streams_utf8() {
+        use std::io::Cursor;
+
+        let dfa = ends_in_one();
+        assert!(dfa
+            .run_reader(Cursor::new("0101".repeat(100_000) + "1"))
+            .unwrap());
+        assert!(!dfa.run_reader(Cursor::new("0110")).unwrap());
+        assert!(!dfa.run_reader(Cursor::new("01x1")).unwrap());
+
+        let dfa =
+            DFA::from_string("alphabet={ñ,é}\nstate={a}\nstart_state=a\nF={a}\n(a,ñ)->a\n(a,é)->a")
+                .unwrap();
+        assert!(dfa.run_reader(Cursor::new("ñéñ")).unwrap());
+        // Un carácter incompleto o un byte inválido son errores de lectura
+        assert!(dfa.run_reader(Cursor::new(vec![0xC3])).is_err());
+        assert!(dfa.run_reader(Cursor::new(vec![0xFF])).is_err());
+    }
+
+    #[test]
+    fn formatted_definition_of_sample() {
+        let dfa = sample();
+        assert_eq!(dfa.format_states(), "{q0, q1, q1q2, q2}");
+        assert_eq!(dfa.format_alphabet(), "{'0', '1'}");
+        assert_eq!(dfa.format_start_state(), "q0");
+        assert_eq!(dfa.format_accept_states(), "{q1q2, q2}");
+        assert_eq!(
+            dfa.format_transitions(),
+            "δ(q0, 0) = q0\nδ(q0, 1) = q1\nδ(q1, 0) = q1q2\nδ(q1, 1) = q1\n\
+             δ(q1q2, 0) = q1q2\nδ(q1q2, 1) = q1q2\nδ(q2, 0) = q2\nδ(q2, 1) = q1q2\n"
+        );
+        assert_eq!(
+            dfa.format_tupla(),
+            "A = <Q = {q0, q1, q1q2, q2}, Σ = {'0', '1'}, q0, δ, F = {q1q2, q2}>"
+        );
+    }
+
+    #[test]
+    fn transitions_are_sorted_by_state_then_symbol() {
+        let text = "alphabet={1,0}\nstate={z, a, m}\nstart_state=z\nF={a}\n(z,1)->m\n(z,0)->a\n(m,0)->z\n(a,1)->a";
+        let dfa = DFA::from_string(text).unwrap();
+        let transitions = dfa.format_transitions();
+        assert_eq!(
+            transitions,
+            "δ(a, 1) = a\nδ(m, 0) = z\nδ(z, 0) = a\nδ(z, 1) = m\n"
+        );
+        assert_eq!(transitions, dfa.format_transitions());
+        assert_eq!(dfa.to_dot(), dfa.to_dot());
+        assert_eq!(dfa.to_string_format(), dfa.to_string_format());
+    }
+
+    #[test]
+    fn parse_through_from_str() {
+        let dfa: DFA = sample().to_string_format().parse().unwrap();
+        assert!(dfa.run("10"));
+        assert!(!dfa.run("01"));
+        assert!("alphabet={0}\n(q,0)".parse::<DFA>().is_err());
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let dfa = sample();
+        let mut copy = dfa.clone();
+        copy.remove_transition("q0", '0');
+        copy.add_transition("q0", '0', "q1q2").unwrap();
+        assert!(copy.run("0"));
+        assert!(!dfa.run("0"));
+        assert_eq!(dfa.transition("q0", '0').as_deref(), Some("q0"));
+    }
+
+    #[test]
+    fn dangling_start_and_accept_states() {
+        let result = DFA::from_string("alphabet={0}\nstate={a}\nstart_state=q9\nF={a}\n");
+        assert!(matches!(
+            result,
+            Err(DfaParseError::UnknownStartState { line: 3, ref name }) if name == "q9"
+        ));
+
+        let result = DFA::from_string("alphabet={0}\nstate={a}\nstart_state=a\nF={a, q7}\n");
+        assert!(matches!(
+            result,
+            Err(DfaParseError::UnknownAcceptStates { line: 4, ref names }) if names == &["q7"]
+        ));
+    }
+
+    #[test]
+    fn missing_start_state_is_an_error() {
+        let result = DFA::from_string("alphabet={0}\nstate={a}\nF=*\n(a,0)->a");
+        assert!(matches!(result, Err(DfaParseError::MissingStartState)));
+    }
+
+    #[test]
+    fn duplicate_state_names_are_an_error() {
+        let result = DFA::from_string("alphabet={0}\nstate={a, a}\nstart_state=a");
+        assert!(matches!(
+            result,
+            Err(DfaParseError::DuplicateState { line: 2, ref name }) if name == "a"
+        ));
+        // También entre varias líneas `state=`
+        let result = DFA::from_string("alphabet={0}\nstate={a, b}\nstart_state=a\nstate={b}");
+        assert!(matches!(
+            result,
+            Err(DfaParseError::DuplicateState { line: 4, ref name }) if name == "b"
+        ));
+    }
+
+    #[test]
+    fn spaces_around_names_are_ignored() {
+        let dfa = DFA::from_string(
+            "alphabet={0}\nstate= { q 0 ,  q1 } \nstart_state=   q 0  \nF= {  q1 }\n( q 0 , 0 )-> q1\n(q1,0)->q 0",
+        )
+        .unwrap();
+        assert_eq!(dfa.format_start_state(), "q 0");
+        assert!(dfa.run("0"));
+        assert!(!dfa.run("00"));
+    }
+
+    #[test]
+    fn to_csv_of_sample() {
+        assert_eq!(
+            sample().to_csv(),
+            ",0,1\n->q0,q0,q1\nq1,q1q2,q1\n*q1q2,q1q2,q1q2\n*q2,q2,q1q2\n"
+        );
+        // Las celdas con comas van entre comillas y las transiciones faltantes quedan vacías
+        let dfa = ends_in_one().intersection(&even_length());
+        let mut partial = dfa.clone();
+        partial.remove_transition("a,e", '0');
+        assert!(
+            partial.to_csv().starts_with(",0,1\n\"->a,e\",,\"b,o\"\n"),
+            "{}",
+            partial.to_csv()
+        );
+    }
+
+    #[test]
+    fn from_csv_round_trips() {
+        let dfa = sample();
+        let parsed = DFA::from_csv(&dfa.to_csv()).unwrap();
+        assert!(parsed.equivalent(&dfa));
+        assert_eq!(parsed.to_csv(), dfa.to_csv());
+
+        let product = ends_in_one().intersection(&even_length());
+        let parsed = DFA::from_csv(&product.to_csv()).unwrap();
+        assert!(parsed.equivalent(&product));
+
+        // Espacios alrededor de las celdas y celdas vacías
+        let dfa = DFA::from_csv(" ,a,b\n*->x, y ,\ny,x,\n\"z,w\",,\n").unwrap();
+        assert_eq!(dfa.format_start_state(), "x");
+        assert!(dfa.run("aa") && !dfa.run("a"));
+    }
+
+    #[test]
+    fn from_csv_errors() {
+        assert!(matches!(
+            DFA::from_csv(",a\nx,x\n"),
+            Err(DfaError::MissingStartState)
+        ));
+        assert!(matches!(
+            DFA::from_csv(",a\n->x,x\nx,x\n"),
+            Err(DfaError::DuplicateState(ref name)) if name == "x"
+        ));
+        assert!(matches!(
+            DFA::from_csv(",a\n->x,q9\n"),
+            Err(DfaError::UnknownState(ref name)) if name == "q9"
+        ));
+        assert!(matches!(
+            DFA::from_csv(",a\n->x,x,x\n"),
+            Err(DfaError::MalformedCsv { line: 2, .. })
+        ));
+        assert!(matches!(
+            DFA::from_csv(",a\n->x,x\n->y,y\n"),
+            Err(DfaError::MalformedCsv { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn to_tikz_declares_nodes_and_edges() {
+        let tikz = sample().to_tikz();
+        assert!(tikz.starts_with("\\begin{tikzpicture}"));
+        assert!(tikz.contains("    \\node[state, initial] (s0) at (0, 0) {q0};\n"));
+        assert!(tikz.contains("    \\node[state] (s1) at (2.5, 0) {q1};\n"));
+        assert!(tikz.contains("    \\node[state, accepting] (s2) at (5, 0) {q1q2};\n"));
+        assert!(tikz.contains("    \\path[->] (s0) edge [bend left] node {1} (s1);\n"));
+        assert!(tikz.contains("    \\path[->] (s2) edge [loop above] node {0, 1} (s2);\n"));
+        assert!(tikz.ends_with("\\end{tikzpicture}\n"));
+
+        // Los nombres se escapan para LaTeX
+        let dfa = DFA::from_string("alphabet={a}\nstate={s_1}\nstart_state=s_1\nF={}").unwrap();
+        assert!(dfa.to_tikz().contains("{s\\_1}"));
+    }
+
+    #[test]
+    fn mealy_machine_inverts_bits() {
+        let machine = MealyMachine::from_string(
+            "alphabet={0,1}\nstate={q}\nstart_state=q\n(q, 0)->q / 1\n(q, 1)->q / 0",
+        )
+        .unwrap();
+        assert_eq!(machine.transduce("0110").as_deref(), Some("1001"));
+        assert_eq!(machine.transduce("").as_deref(), Some(""));
+        assert_eq!(machine.transduce("012"), None);
+
+        let result = MealyMachine::from_string("alphabet={0}\nstate={q}\nstart_state=q\n(q, 0)->q");
+        assert!(matches!(
+            result,
+            Err(DfaParseError::MissingOutput { line: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn longest_accepted_prefix_for_lexing() {
+        let number = DFA::from_regex("(0|1|2|3|4|5|6|7|8|9)+").unwrap();
+        assert_eq!(
+            number.longest_accepted_prefix("123abc"),
+            Some(("123".to_string(), 3))
+        );
+        assert_eq!(number.longest_accepted_prefix("abc"), None);
+        // La palabra vacía es un prefijo válido si el estado inicial acepta
+        let optional = DFA::from_regex("(a|ab)?").unwrap();
+        assert_eq!(
+            optional.longest_accepted_prefix("x"),
+            Some((String::new(), 0))
+        );
+        // La posición se cuenta en bytes
+        let accented = DFA::from_regex("éé*").unwrap();
+        assert_eq!(
+            accented.longest_accepted_prefix("ééx"),
+            Some(("éé".to_string(), 4))
+        );
+    }
+
+    #[test]
+    fn accepts_any_prefix_stops_early() {
+        let dfa = DFA::from_regex("ab").unwrap();
+        assert!(dfa.accepts_any_prefix("abzzz"));
+        assert!(!dfa.accepts_any_prefix("a"));
+        assert!(!dfa.accepts_any_prefix("ba"));
+        assert!(DFA::from_regex("a*").unwrap().accepts_any_prefix("zzz"));
+    }
+
+    #[test]
+    fn sccs_of_sample() {
+        let components = sample().sccs();
+        // Cada estado forma su propia componente; el sumidero q1q2 aparece primero
+        assert_eq!(components.len(), 4);
+        assert_eq!(components[0], ["q1q2"]);
+        for name in ["q0", "q1", "q2"] {
+            assert!(components.contains(&vec![name.to_string()]), "{}", name);
+        }
+
+        let cycle = DFA::from_string(
+            "alphabet={a,b}\nstate={x,y,z}\nstart_state=x\nF={z}\n(x,a)->y\n(y,a)->x\n(y,b)->z",
+        )
+        .unwrap();
+        let components = cycle.sccs();
+        assert!(components.contains(&vec!["x".to_string(), "y".to_string()]));
+        assert!(components.contains(&vec!["z".to_string()]));
+    }
+
+    #[test]
+    fn equivalence_classes_match_minimize() {
+        let classes = sample().equivalence_classes();
+        // minimize fusiona q1q2 y q2
+        assert!(classes.contains(&vec!["q1q2".to_string(), "q2".to_string()]));
+        assert_eq!(classes.len(), sample().minimize().state_count());
+
+        let regex = DFA::from_regex("(a|b)*abb").unwrap();
+        let redundant = regex.union(&regex);
+        assert_eq!(
+            redundant.equivalence_classes().len(),
+            redundant.minimize().state_count()
+        );
+    }
+
+    #[test]
+    fn canonicalize_names_product_states() {
+        let a = DFA::from_regex("(0|1)*1").unwrap();
+        let b = DFA::from_regex("(0|1)*0").unwrap();
+        // El mismo lenguaje construido de dos maneras
+        let first = a.intersection(&b.complement()).canonicalize();
+        let second = a.difference(&b).canonicalize();
+        assert_eq!(first.to_string_format(), second.to_string_format());
+        assert_eq!(first.format_start_state(), "q0");
+        assert!(first.states().all(|name| name.starts_with('q')));
+
+        // Los inalcanzables se numeran al final
+        let dfa = DFA::from_string("alphabet={a}\nstate={u, z, y}\nstart_state=y\nF={y}\n(y,a)->z")
+            .unwrap()
+            .canonicalize();
+        assert_eq!(dfa.states().collect::<Vec<_>>(), ["q0", "q1", "q2"]);
+        assert_eq!(dfa.transition("q0", 'a').as_deref(), Some("q1"));
+    }
+
+    #[test]
+    fn rename_state_keeps_behavior() {
+        let mut dfa = sample();
+        let before: Vec<bool> = words(&['0', '1'], 5).iter().map(|w| dfa.run(w)).collect();
+        dfa.rename_state("q0", "inicio").unwrap();
+        assert_eq!(dfa.format_start_state(), "inicio");
+        let after: Vec<bool> = words(&['0', '1'], 5).iter().map(|w| dfa.run(w)).collect();
+        assert_eq!(before, after);
+
+        assert!(matches!(
+            dfa.rename_state("q0", "x"),
+            Err(DfaError::UnknownState(_))
+        ));
+        assert!(matches!(
+            dfa.rename_state("q1", "q2"),
+            Err(DfaError::DuplicateState(_))
+        ));
+    }
+
+    #[test]
+    fn run_case_insensitive_with_lowercase_alphabet() {
+        let keyword = DFA::from_regex("while").unwrap();
+        assert!(keyword.run_case_insensitive("WhIlE"));
+        assert!(!keyword.run("WhIlE"));
+        assert!(!keyword.run_case_insensitive("WhIlEs"));
+        // `ẞ` se convierte en `ß`; `İ` se convierte en dos caracteres y se compara tal cual
+        assert!(DFA::from_regex("ß").unwrap().run_case_insensitive("ẞ"));
+        assert!(DFA::from_regex("İ").unwrap().run_case_insensitive("İ"));
+    }
+
+    #[test]
+    fn iter_language_is_lazy_and_ordered() {
+        let dfa = DFA::from_regex("(0|1)*1").unwrap();
+        let enumerated = dfa.accepted_words(4);
+        let lazy: Vec<String> = dfa.iter_language().take(enumerated.len()).collect();
+        assert_eq!(lazy, enumerated);
+        // El lenguaje es infinito: se puede pedir cualquier posición
+        assert_eq!(dfa.iter_language().nth(1000).unwrap().len(), 10);
+
+        // Un lenguaje finito agota el iterador
+        let finite = DFA::from_regex("ab|c|abc").unwrap().complete_with_trap();
+        assert_eq!(
+            finite.iter_language().collect::<Vec<_>>(),
+            ["c", "ab", "abc"]
+        );
+        assert_eq!(DFA::from_regex("∅").unwrap().iter_language().next(), None);
+    }
+
+    #[test]
+    fn contains_language_checks_inclusion() {
+        let ends_in_1 = DFA::from_regex("(0|1)*1").unwrap();
+        let ends_in_11 = DFA::from_regex("(0|1)*11").unwrap();
+        assert!(ends_in_1.contains_language(&ends_in_11));
+        assert!(!ends_in_11.contains_language(&ends_in_1));
+        assert!(ends_in_1.contains_language(&ends_in_1));
+
+        let starts_with_0 = DFA::from_regex("0(0|1)*").unwrap();
+        assert!(!starts_with_0.contains_language(&ends_in_1));
+        assert!(!ends_in_1.contains_language(&starts_with_0));
+    }
+
+    #[test]
+    fn pumping_length_is_minimal_state_count() {
+        let dfa = sample();
+        assert_eq!(dfa.pumping_length(), dfa.minimize().state_count());
+
+        // Toda palabra aceptada de longitud al menos p se puede bombear dentro de sus primeros p símbolos
+        let dfa = DFA::from_regex("(0|1)*11").unwrap();
+        let p = dfa.pumping_length();
+        for word in dfa.accepted_words(p + 3) {
+            if word.len() < p {
+                continue;
+            }
+            let pumpable = (0..p).any(|i| {
+                (i + 1..=p).any(|j| {
+                    (0..4).all(|k| {
+                        let pumped =
+                            format!("{}{}{}", &word[..i], word[i..j].repeat(k), &word[j..]);
+                        dfa.run(&pumped)
+                    })
+                })
+            });
+            assert!(pumpable, "{}", word);
+        }
+    }
+
+    #[test]
+    fn concatenate_joins_languages() {
+        // {0, 00} · {1}
+        let first = DFA::from_string(
+            "alphabet={0}\nstate={a,b,c}\nstart_state=a\nF={b,c}\n(a,0)->b\n(b,0)->c",
+        )
+        .unwrap();
+        let second =
+            DFA::from_string("alphabet={1}\nstate={x,y}\nstart_state=x\nF={y}\n(x,1)->y").unwrap();
+        let concatenation = first.concatenate(&second);
+        assert_eq!(concatenation.accepted_words(6), ["01", "001"]);
+        assert_eq!(concatenation.alphabet, ['0', '1']);
+    }
+
+    #[test]
+    fn kleene_star_repeats_words() {
+        // {01}
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,c}\nstart_state=a\nF={c}\n(a,0)->b\n(b,1)->c",
+        )
+        .unwrap();
+        let star = dfa.kleene_star();
+        for word in ["", "01", "0101", "010101"] {
+            assert!(star.run(word), "{}", word);
+        }
+        for word in ["0", "010", "1", "0110", "10"] {
+            assert!(!star.run(word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn canonical_fingerprint_of_equivalent_dfas() {
+        // Palabras que terminan en 1, con estados redundantes en el segundo
+        let redundant = DFA::from_string(
+            "alphabet={0,1}\nstate={x,y,z,w}\nstart_state=x\nF={y,z}\n(x,0)->w\n(x,1)->y\n(y,0)->x\n(y,1)->z\n(z,0)->w\n(z,1)->y\n(w,0)->x\n(w,1)->z",
+        )
+        .unwrap();
+        let from_regex = DFA::from_regex("(0|1)*1").unwrap();
+        assert_eq!(
+            ends_in_one().canonical_fingerprint(),
+            redundant.canonical_fingerprint()
+        );
+        assert_eq!(
+            ends_in_one().canonical_fingerprint(),
+            from_regex.canonical_fingerprint()
+        );
+        assert_ne!(
+            ends_in_one().canonical_fingerprint(),
+            ends_in_one().complement().canonical_fingerprint()
+        );
+
+        // Completar un autómata parcial no cambia su lenguaje
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b")
+                .unwrap();
+        assert_eq!(
+            partial.canonical_fingerprint(),
+            partial.complete_with_trap().canonical_fingerprint()
+        );
+    }
+
+    #[test]
+    fn warnings_report_unreachable_and_dead_states() {
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,z}\nstart_state=a\nF={b}\n(a,0)->a\n(a,1)->b\n(b,0)->a\n(b,1)->b",
+        )
+        .unwrap();
+        assert_eq!(
+            dfa.warnings(),
+            [DfaWarning::UnreachableState("z".to_string())]
+        );
+
+        let dfa =
+            DFA::from_string("alphabet={0}\nstate={a,t}\nstart_state=a\nF={a}\n(a,0)->t\n(t,0)->t")
+                .unwrap();
+        assert_eq!(dfa.warnings(), [DfaWarning::DeadState("t".to_string())]);
+        assert!(ends_in_one().warnings().is_empty());
+    }
+
+    #[test]
+    fn all_unknown_accept_states_are_reported() {
+        let error = DFA::from_string("alphabet={0}\nstate={a,b}\nstart_state=a\nF={a, q5, q9}\n")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::UnknownAcceptStates { line: 4, ref names } if names == &["q5", "q9"]
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Línea 4: los estados de aceptación \"q5\", \"q9\" no existen"
+        );
+    }
+
+    #[test]
+    fn multibyte_symbols_are_parsed() {
+        let dfa = DFA::from_string(
+            "alphabet={α, β, ∅}\nstate={a,b,c,d}\nstart_state=a\nF={d}\n(a, α)->b\n(b, β)->c\n(c, ∅)->d",
+        )
+        .unwrap();
+        assert!(dfa.run("αβ∅"));
+        assert!(!dfa.run("αβ"));
+
+        let error = DFA::from_string("alphabet={α, β}\nstate={a}\nstart_state=a\n(a, αβ)->a")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MalformedSymbol { line: 4, ref text } if text == "αβ"
+        ));
+
+        // Un grafema de dos puntos de código no es un único símbolo
+        let error = DFA::from_string("alphabet={e}\nstate={a}\nstart_state=a\n(a, e\u{301})->a")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MalformedSymbol { line: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn map_alphabet_renames_symbols() {
+        let mapping: HashMap<char, char> = [('0', 'a'), ('1', 'b')].into_iter().collect();
+        let mapped = ends_in_one().map_alphabet(&mapping).unwrap();
+        assert_eq!(mapped.alphabet, ['a', 'b']);
+        assert!(mapped.run("aab"));
+        assert!(!mapped.run("ba"));
+        assert!(!mapped.run("01"));
+
+        // Fusionar 0 y 1 hace que a tenga dos destinos distintos para x
+        let merge: HashMap<char, char> = [('0', 'x'), ('1', 'x')].into_iter().collect();
+        assert!(matches!(
+            ends_in_one().map_alphabet(&merge),
+            Err(DfaError::DuplicateTransition { .. })
+        ));
+        let missing: HashMap<char, char> = [('0', 'x')].into_iter().collect();
+        assert!(matches!(
+            ends_in_one().map_alphabet(&missing),
+            Err(DfaError::UnknownSymbol('1'))
+        ));
+
+        // Si los destinos coinciden, fusionar símbolos sigue siendo determinista
+        let loop_dfa =
+            DFA::from_string("alphabet={0,1}\nstate={a}\nstart_state=a\nF={a}\n(a,0)->a\n(a,1)->a")
+                .unwrap();
+        assert!(loop_dfa.map_alphabet(&merge).unwrap().run("xx"));
+    }
+
+    #[test]
+    fn length_histogram_matches_enumeration() {
+        let dfa = sample();
+        let histogram = dfa.length_histogram(6);
+        assert_eq!(histogram.len(), 7);
+        assert_eq!(
+            histogram.iter().sum::<u64>() as usize,
+            dfa.accepted_words(6).len()
+        );
+        for (length, &count) in histogram.iter().enumerate() {
+            assert_eq!(count, dfa.count_accepted(length));
+        }
+    }
+
+    #[test]
+    fn accepts_empty_checks_start_state() {
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,c}\nstart_state=a\nF={c}\n(a,0)->b\n(b,1)->c",
+        )
+        .unwrap();
+        assert!(!dfa.accepts_empty());
+        assert!(dfa.kleene_star().accepts_empty());
+        assert!(dfa.complement().accepts_empty());
+        assert!(even_length().accepts_empty());
+        assert!(!sample().accepts_empty());
+    }
+
+    #[test]
+    fn is_universal_requires_complete_accepting_states() {
+        let universal =
+            DFA::from_string("alphabet={0,1}\nstate={a}\nstart_state=a\nF={a}\n(a,0)->a\n(a,1)->a")
+                .unwrap();
+        assert!(universal.is_universal());
+        assert!(!sample().is_universal());
+        assert_eq!(sample().is_universal(), sample().complement().is_empty());
+
+        // Una transición faltante rechaza las palabras con ese símbolo
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a}\nstart_state=a\nF={a}\n(a,0)->a").unwrap();
+        assert!(!partial.is_universal());
+    }
+
+    #[test]
+    fn residual_accepts_completing_suffixes() {
+        let dfa = sample();
+        let residual = dfa.residual("0").unwrap();
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(
+                residual.run(&word),
+                dfa.run(&format!("0{}", word)),
+                "{}",
+                word
+            );
+        }
+        assert!(dfa.residual("10").unwrap().accepts_empty());
+        assert!(dfa.residual("2").is_none());
+
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b")
+                .unwrap();
+        assert!(partial.residual("0").is_none());
+    }
+
+    #[test]
+    fn transition_fn_follows_run() {
+        let dfa = sample();
+        let delta = dfa.as_transition_fn();
+        assert_eq!(delta("q0", '1').as_deref(), Some("q1"));
+        assert_eq!(delta("q1", '0').as_deref(), Some("q1q2"));
+        assert_eq!(delta("q2", '1').as_deref(), Some("q1q2"));
+        assert_eq!(delta("q9", '1'), None);
+        assert_eq!(delta("q0", '2'), None);
+        assert_eq!(dfa.transition("q0", '0').as_deref(), Some("q0"));
+
+        let mut state = dfa.start_name().to_string();
+        for symbol in "1001".chars() {
+            state = delta(&state, symbol).unwrap();
+        }
+        assert_eq!(dfa.is_accepting_state(&state), Some(dfa.run("1001")));
+    }
+
+    #[test]
+    fn exporters_are_deterministic() {
+        let exports = |dfa: &DFA| {
+            vec![
+                dfa.to_dot(),
+                dfa.to_csv(),
+                dfa.to_string_format(),
+                dfa.format_tupla(),
+                dfa.format_transitions(),
+                dfa.to_tikz(),
+                dfa.to_mermaid(),
+            ]
+        };
+
+        // Cada exportador llamado dos veces produce exactamente los mismos bytes
+        let dfa = sample().intersection(&sample().complement());
+        assert_eq!(exports(&dfa), exports(&dfa));
+
+        // El orden de las líneas del texto no influye en la salida
+        let shuffled = DFA::from_string(
+            "alphabet={1,0}\nstate={z, q1, a}\nstart_state=q1\nF={z, a}\n(z,1)->a\n(q1,0)->z\n(a,0)->q1\n(q1,1)->a",
+        )
+        .unwrap();
+        let ordered = DFA::from_string(
+            "alphabet={0,1}\nstate={a, q1, z}\nstart_state=q1\nF={a, z}\n(a,0)->q1\n(q1,0)->z\n(q1,1)->a\n(z,1)->a",
+        )
+        .unwrap();
+        assert_eq!(exports(&shuffled), exports(&shuffled));
+        assert_eq!(exports(&shuffled), exports(&ordered));
+        assert_eq!(
+            shuffled.format_tupla(),
+            "A = <Q = {a, q1, z}, Σ = {'0', '1'}, q1, δ, F = {a, z}>"
+        );
+    }
+
+    #[test]
+    fn accepting_positions_match_prefix_runs() {
+        // Múltiplos de 3 en binario
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={r0,r1,r2}\nstart_state=r0\nF={r0}\n(r0,0)->r0\n(r0,1)->r1\n(r1,0)->r2\n(r1,1)->r0\n(r2,0)->r1\n(r2,1)->r2",
+        )
+        .unwrap();
+        let input: String = (0..200)
+            .map(|i| if (i * 7 + i / 3) % 5 < 2 { '1' } else { '0' })
+            .collect();
+        let expected: Vec<usize> = (0..=input.len())
+            .filter(|&end| dfa.run(&input[..end]))
+            .collect();
+        assert_eq!(dfa.accepting_positions(&input), expected);
+
+        // Se detiene en el primer símbolo sin transición
+        assert_eq!(dfa.accepting_positions("11x11"), [0, 2]);
+        assert!(sample().accepting_positions("0").is_empty());
+    }
+
+    #[test]
+    fn wildcard_transitions_fill_remaining_symbols() {
+        let dfa = DFA::from_string(
+            "alphabet={a,b,c,d,e}\nstate={q0,q1,q9}\nstart_state=q0\nF={q1}\n(q0, *)->q9\n(q0, a)->q1\n(q1, *)->q1\n(q9, *)->q9",
+        )
+        .unwrap();
+        assert!(dfa.is_complete());
+        assert_eq!(dfa.transition("q0", 'a').as_deref(), Some("q1"));
+        for symbol in ['b', 'c', 'd', 'e'] {
+            assert_eq!(dfa.transition("q0", symbol).as_deref(), Some("q9"));
+        }
+        assert!(dfa.run("abcde"));
+        assert!(!dfa.run("ba"));
+
+        let error = DFA::from_string(
+            "alphabet={a,b}\nstate={q0,q1}\nstart_state=q0\n(q0, *)->q1\n(q0, *)->q0",
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::DuplicateWildcard { line: 5, ref state } if state == "q0"
+        ));
+        let error = DFA::from_string("alphabet={a,b}\nstate={q0}\nstart_state=q0\n(q0, *)->q7")
+            .err()
+            .unwrap();
+        assert!(matches!(error, DfaParseError::UnknownState { line: 4, .. }));
+
+        // Si `*` pertenece al alfabeto la transición es literal
+        let literal =
+            DFA::from_string("alphabet={a,*}\nstate={q0,q1}\nstart_state=q0\nF={q1}\n(q0, *)->q1")
+                .unwrap();
+        assert!(literal.run("*"));
+        assert!(!literal.run("a"));
+    }
+
+    #[test]
+    fn merge_sinks_collapses_traps() {
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,t1,t2,u}\nstart_state=a\nF={b,u}\n(a,0)->t1\n(a,1)->b\n(b,0)->t2\n(b,1)->u\n(t1,0)->t1\n(t1,1)->t1\n(t2,0)->t2\n(t2,1)->t2\n(u,0)->u\n(u,1)->u",
+        )
+        .unwrap();
+        let merged = dfa.merge_sinks();
+        assert_eq!(merged.state_count(), 4);
+        assert!(merged.equivalent(&dfa));
+        assert_eq!(merged.transition("b", '0').as_deref(), Some("t1"));
+
+        let complemented = sample().union(&sample().complement());
+        assert!(complemented.merge_sinks().equivalent(&complemented));
+        assert!(complemented.merge_sinks().state_count() <= complemented.state_count());
+    }
+
+    #[test]
+    fn multiple_accept_lines_are_merged() {
+        let dfa = DFA::from_string(
+            "alphabet={0}\nstate={a,b,c}\nstart_state=a\nF={b}\n(a,0)->b\nF={c, b}\n(b,0)->c",
+        )
+        .unwrap();
+        assert_eq!(dfa.format_accept_states(), "{b, c}");
+        assert!(dfa.run("0"));
+        assert!(dfa.run("00"));
+        assert!(!dfa.run(""));
+
+        let error = DFA::from_string("alphabet={0}\nstate={a}\nstart_state=a\nF={a}\nF={x, y}")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::UnknownAcceptStates { line: 5, ref names } if names == &["x", "y"]
+        ));
+    }
+
+    #[test]
+    fn tupla_diagnostics_report_missing_transitions() {
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b")
+                .unwrap();
+        let text = partial.format_tupla_with_diagnostics();
+        assert_eq!(
+            text,
+            format!(
+                "{}\n(parcial: faltan 3 transiciones)",
+                partial.format_tupla()
+            )
+        );
+        assert_eq!(
+            sample().format_tupla_with_diagnostics(),
+            format!("{}\n(completo)", sample().format_tupla())
+        );
+        assert!(!sample().format_tupla().contains("completo"));
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bytes_round_trip_is_smaller_than_text() {
+        let dfa = sample();
+        let back = DFA::<char>::from_bytes(&dfa.to_bytes()).unwrap();
+        assert_eq!(back.to_string_format(), dfa.to_string_format());
+        assert!(matches!(
+            DFA::<char>::from_bytes(&[1, 2, 3]),
+            Err(DfaError::MalformedBinary(_))
+        ));
+
+        // Contador módulo 3000: el 1 avanza y el 0 se queda en el mismo estado
+        let n = 3000;
+        let names: Vec<String> = (0..n).map(|i| format!("s{}", i)).collect();
+        let mut text = format!(
+            "alphabet={{0,1}}\nstate={{{}}}\nstart_state=s0\nF={{s0}}\n",
+            names.join(",")
+        );
+        for i in 0..n {
+            text.push_str(&format!(
+                "(s{}, 0)->s{}\n(s{}, 1)->s{}\n",
+                i,
+                i,
+                i,
+                (i + 1) % n
+            ));
+        }
+        let big = DFA::from_string(&text).unwrap();
+        let bytes = big.to_bytes();
+        assert!(bytes.len() < text.len());
+
+        assert!(DFA::<char>::from_bytes(&bytes).unwrap().equivalent(&big));
+    }
+
+    #[test]
+    fn product_uses_alphabet_union() {
+        // Sobre {0,1} termina en 1; sobre {0,1,2} no contiene ningún 2
+        let ends_in_one = ends_in_one();
+        let no_two = DFA::from_string(
+            "alphabet={0,1,2}\nstate={p,q}\nstart_state=p\nF={p}\n(p,0)->p\n(p,1)->p\n(p,2)->q\n(q,0)->q\n(q,1)->q\n(q,2)->q",
+        )
+        .unwrap();
+
+        let intersection = ends_in_one.intersection(&no_two);
+        assert_eq!(intersection.alphabet, ['0', '1', '2']);
+        for word in words(&['0', '1', '2'], 4) {
+            let in_first = word.chars().all(|c| c != '2') && ends_in_one.run(&word);
+            assert_eq!(
+                intersection.run(&word),
+                in_first && no_two.run(&word),
+                "{}",
+                word
+            );
+            assert_eq!(
+                ends_in_one.union(&no_two).run(&word),
+                in_first || no_two.run(&word),
+                "{}",
+                word
+            );
+        }
+        assert!(intersection.run("0101"));
+        assert!(!intersection.run("21"));
+        assert!(no_two.difference(&ends_in_one).run("10"));
+        assert!(!ends_in_one.equivalent(&no_two));
+    }
+
+    #[test]
+    fn language_diff_lists_changed_words() {
+        let old = sample();
+        // Cambiar (q0, 1)->q1 por (q0, 1)->q2
+        let new = DFA::from_string(
+            &old.to_string_format()
+                .replace("(q0, 1)->q1\n", "(q0, 1)->q2\n"),
+        )
+        .unwrap();
+        let diff = new.language_diff(&old, 4);
+        for word in words(&['0', '1'], 4) {
+            let (now, before) = (new.run(&word), old.run(&word));
+            assert_eq!(
+                diff.newly_accepted.contains(&word),
+                now && !before,
+                "{}",
+                word
+            );
+            assert_eq!(
+                diff.newly_rejected.contains(&word),
+                before && !now,
+                "{}",
+                word
+            );
+        }
+        assert!(!diff.newly_accepted.is_empty());
+        assert_eq!(
+            old.language_diff(&old, 5),
+            LanguageDiff {
+                newly_accepted: vec![],
+                newly_rejected: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn byte_dfa_runs_raw_frames() {
+        // Tramas que empiezan con 0x7E, siguen con bytes 0x00 o `a` y terminan con 0xFF
+        let dfa = DFA::from_byte_string(
+            "alphabet={\\x7E, \\x00, a, \\xff}\nstate={s,body,end}\nstart_state=s\nF={end}\n(s, \\x7E)->body\n(body, \\x00)->body\n(body, a)->body\n(body, \\xFF)->end",
+        )
+        .unwrap();
+        assert_eq!(dfa.alphabet, [0x00, b'a', 0x7E, 0xFF]);
+        assert!(dfa.run_bytes(&[0x7E, 0, b'a', 0, 0xFF]));
+        assert!(dfa.run_bytes(&[0x7E, 0xFF]));
+        assert!(!dfa.run_bytes(&[0x7E, 0x01, 0xFF]));
+        assert!(!dfa.run_bytes(&[0x7E, 0xFF, 0xFF]));
+        assert_eq!(
+            dfa.run_bytes(&[0x7E, 0, 0xFF]),
+            dfa.run_symbols(&[0x7E, 0, 0xFF])
+        );
+
+        let error = DFA::from_byte_string("alphabet={a}\nstate={s}\nstart_state=s\n(s, \\xZZ)->s")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MalformedSymbol { line: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn explain_reports_each_outcome() {
+        let dfa = sample();
+        assert_eq!(dfa.explain("10"), RunExplanation::Accepted);
+        assert_eq!(
+            dfa.explain("0011"),
+            RunExplanation::RejectedAtEnd {
+                final_state: "q1".to_string()
+            }
+        );
+        assert_eq!(
+            dfa.explain("0121"),
+            RunExplanation::NoTransition {
+                position: 2,
+                state: "q1".to_string(),
+                symbol: '2',
+            }
+        );
+
+        // La posición se cuenta en bytes
+        let partial =
+            DFA::from_string("alphabet={a,b}\nstate={s,t}\nstart_state=s\nF={t}\n(s,a)->t")
+                .unwrap();
+        assert_eq!(
+            partial.explain("ñb"),
+            RunExplanation::NoTransition {
+                position: 0,
+                state: "s".to_string(),
+                symbol: 'ñ',
+            }
+        );
+        assert_eq!(
+            partial.explain("aaa"),
+            RunExplanation::NoTransition {
+                position: 1,
+                state: "t".to_string(),
+                symbol: 'a',
+            }
+        );
+    }
+
+    #[test]
+    fn mermaid_marks_start_and_accepting_states() {
+        let mermaid = sample().to_mermaid();
+        assert!(mermaid.starts_with("stateDiagram-v2\n"));
+        assert!(mermaid.contains("    [*] --> s0\n"));
+        assert!(mermaid.contains("classDef accepting"));
+        assert!(mermaid.contains("    class s2,s3 accepting\n"));
+        // Las transiciones paralelas se agrupan en una sola arista
+        assert!(mermaid.contains("    s2 --> s2: 0, 1\n"));
+    }
+
+    #[test]
+    fn add_and_remove_transitions() {
+        let mut dfa =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,0)->a")
+                .unwrap();
+        assert!(!dfa.run("1"));
+        dfa.add_transition("a", '1', "b").unwrap();
+        assert!(dfa.run("01"));
+
+        assert!(matches!(
+            dfa.add_transition("a", '1', "a"),
+            Err(DfaError::DuplicateTransition { .. })
+        ));
+        assert!(matches!(
+            dfa.add_transition("a", '2', "a"),
+            Err(DfaError::UnknownSymbol('2'))
+        ));
+        assert!(matches!(
+            dfa.add_transition("x", '0', "a"),
+            Err(DfaError::UnknownState(_))
+        ));
+        assert!(matches!(
+            dfa.add_transition("b", '0', "y"),
+            Err(DfaError::UnknownState(_))
+        ));
+
+        assert!(dfa.remove_transition("a", '1'));
+        assert!(!dfa.remove_transition("a", '1'));
+        assert!(!dfa.remove_transition("a", '9'));
+        assert!(!dfa.run("01"));
+    }
+
+    #[test]
+    fn add_and_remove_states() {
+        let mut dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={x,a,b,c}\nstart_state=b\nF={c}\n(x,0)->c\n(a,0)->b\n(b,0)->a\n(b,1)->c\n(c,1)->a\n(c,0)->x",
+        )
+        .unwrap();
+        assert!(matches!(
+            dfa.add_state("a", true),
+            Err(DfaError::DuplicateState(_))
+        ));
+        dfa.add_state("n", true).unwrap();
+        assert_eq!(dfa.is_accepting_state("n"), Some(true));
+        assert!(matches!(
+            dfa.remove_state("b"),
+            Err(DfaError::CannotRemoveStartState(_))
+        ));
+        assert!(matches!(
+            dfa.remove_state("zz"),
+            Err(DfaError::UnknownState(_))
+        ));
+
+        // Quitar estados borra también las transiciones que entraban a ellos
+        dfa.remove_state("a").unwrap();
+        dfa.remove_state("x").unwrap();
+        assert_eq!(dfa.format_states(), "{b, c, n}");
+        assert_eq!(dfa.transition("b", '0'), None);
+        assert_eq!(dfa.transition("b", '1').as_deref(), Some("c"));
+        assert_eq!(dfa.transition("c", '0'), None);
+        assert_eq!(dfa.transition("c", '1'), None);
+        assert_eq!(dfa.transition_count(), 1);
+        assert_eq!(dfa.format_start_state(), "b");
+        assert!(dfa.run("1"));
+        assert!(!dfa.run("0"));
+    }
+
+    #[test]
+    fn accept_state_shorthands() {
+        let base = "alphabet={0,1}\nstate={a,b,c}\nstart_state=a\n(a,0)->b\n(b,1)->c\n";
+        let all = DFA::from_string(&format!("{}F=*\n", base)).unwrap();
+        assert_eq!(all.format_accept_states(), "{a, b, c}");
+        assert!(all.run("01"));
+        assert!(!all.run("1"));
+
+        let none = DFA::from_string(&format!("{}F={{}}\n", base)).unwrap();
+        assert!(none.is_empty());
+
+        let error = DFA::from_string(&format!("{}F=\n", base)).err().unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MalformedAcceptStates { line: 6, .. }
+        ));
+
+        // `F=*` antes de `state=` también marca los estados declarados después
+        let early =
+            DFA::from_string("alphabet={0}\nF=*\nstate={a,b}\nstart_state=a\n(a,0)->b").unwrap();
+        assert_eq!(early.format_accept_states(), "{a, b}");
+    }
+
+    #[test]
+    fn relabel_by_language_residual_is_canonical() {
+        // Número par de unos, con nombres distintos y un estado inalcanzable en el segundo
+        let pair = DFA::from_string(
+            "alphabet={0,1}\nstate={p,i}\nstart_state=p\nF={p}\n(p,0)->p\n(p,1)->i\n(i,0)->i\n(i,1)->p",
+        )
+        .unwrap();
+        let redundant = DFA::from_string(
+            "alphabet={0,1}\nstate={x,y,z,w}\nstart_state=z\nF={z,x}\n(z,0)->x\n(z,1)->y\n(x,0)->z\n(x,1)->y\n(y,0)->y\n(y,1)->x\n(w,0)->w\n(w,1)->w",
+        )
+        .unwrap();
+        let relabeled = pair.relabel_by_language_residual();
+        assert_eq!(relabeled.format_states(), "{1, ε}");
+        assert_eq!(
+            relabeled.to_string_format(),
+            redundant.relabel_by_language_residual().to_string_format()
+        );
+
+        // Un autómata parcial y su versión completa reciben los mismos nombres
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b")
+                .unwrap();
+        let relabeled = partial.relabel_by_language_residual();
+        assert_eq!(relabeled.format_states(), "{0, 1, ε}");
+        assert_eq!(
+            relabeled.to_string_format(),
+            partial
+                .complete_with_trap()
+                .relabel_by_language_residual()
+                .to_string_format()
+        );
+        assert_eq!(
+            sample().relabel_by_language_residual().to_string_format(),
+            sample()
+                .minimize()
+                .relabel_by_language_residual()
+                .to_string_format()
+        );
+    }
+
+    #[test]
+    fn enumeration_budgets_and_saturation() {
+        let dfa = sample();
+        assert_eq!(dfa.accepted_words_limited(6, 5), dfa.accepted_words(6)[..5]);
+        assert_eq!(dfa.accepted_words_limited(3, 1000), dfa.accepted_words(3));
+        assert!(dfa.accepted_words_limited(6, 0).is_empty());
+
+        // Solo acepta palabras de longitud 22: con max_len 3 no hay que explorar más allá
+        let mut text = String::from("alphabet={0,1}\nstate={");
+        text.push_str(
+            &(0..=22)
+                .map(|i| format!("c{}", i))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        text.push_str("}\nstart_state=c0\nF={c22}\n");
+        for i in 0..22 {
+            text.push_str(&format!(
+                "(c{}, 0)->c{}\n(c{}, 1)->c{}\n",
+                i,
+                i + 1,
+                i,
+                i + 1
+            ));
+        }
+        let long_words = DFA::from_string(&text).unwrap();
+        let start = std::time::Instant::now();
+        assert!(long_words.accepted_words_limited(3, 10).is_empty());
+        assert!(start.elapsed() < std::time::Duration::from_millis(100));
+
+        // Σ* sobre {0,1} tiene 2^64 palabras de longitud 64, que no caben en u64
+        let universal =
+            DFA::from_string("alphabet={0,1}\nstate={a}\nstart_state=a\nF={a}\n(a,0)->a\n(a,1)->a")
+                .unwrap();
+        assert_eq!(universal.count_accepted(63), 1 << 63);
+        assert_eq!(universal.count_accepted(64), u64::MAX);
+        assert_eq!(universal.length_histogram(70)[70], u64::MAX);
+    }
+
+    #[test]
+    fn intersection_many_of_three_machines() {
+        // Termina en 1, longitud par y sin dos ceros seguidos
+        let no_double_zero = DFA::from_string(
+            "alphabet={0,1}\nstate={x,y,z}\nstart_state=x\nF={x,y}\n(x,0)->y\n(x,1)->x\n(y,0)->z\n(y,1)->x\n(z,0)->z\n(z,1)->z",
+        )
+        .unwrap();
+        let machines = [ends_in_one(), even_length(), no_double_zero];
+        let intersection = DFA::intersection_many(&machines).unwrap();
+        let union = DFA::union_many(&machines).unwrap();
+        assert_eq!(intersection.start_name(), "a,e,x");
+        for word in words(&['0', '1'], 6) {
+            let results: Vec<bool> = machines.iter().map(|dfa| dfa.run(&word)).collect();
+            assert_eq!(
+                intersection.run(&word),
+                !results.contains(&false),
+                "{}",
+                word
+            );
+            assert_eq!(union.run(&word), results.contains(&true), "{}", word);
+        }
+
+        // Coincide con encadenar el producto de a pares
+        let pairwise = machines[0]
+            .intersection(&machines[1])
+            .intersection(&machines[2]);
+        assert!(intersection.equivalent(&pairwise));
+        assert_eq!(
+            DFA::intersection_many(&machines[..1]).unwrap().start_name(),
+            "a"
+        );
+        assert!(matches!(
+            DFA::<char>::intersection_many(&[]),
+            Err(DfaError::EmptyProduct)
+        ));
+    }
+
+    #[test]
+    fn product_many_uses_alphabet_union() {
+        // Sobre {0,1,2}: sin ningún 2
+        let no_two = DFA::from_string(
+            "alphabet={0,1,2}\nstate={p,q}\nstart_state=p\nF={p}\n(p,0)->p\n(p,1)->p\n(p,2)->q\n(q,0)->q\n(q,1)->q\n(q,2)->q",
+        )
+        .unwrap();
+        let machines = [ends_in_one(), even_length(), no_two];
+        let intersection = DFA::intersection_many(&machines).unwrap();
+        let union = DFA::union_many(&machines).unwrap();
+        assert_eq!(intersection.alphabet, ['0', '1', '2']);
+        assert!(intersection.equivalent(
+            &machines[0]
+                .intersection(&machines[1])
+                .intersection(&machines[2])
+        ));
+        assert!(union.equivalent(&machines[0].union(&machines[1]).union(&machines[2])));
+        assert!(intersection.run("01"));
+        assert!(!intersection.run("21"));
+        // Un 2 lleva a los autómatas sobre {0,1} a su estado trampa
+        assert!(!union.run("2"));
+        assert!(!union.run("22"));
+        assert!(union.run("1"));
+    }
+
+    #[test]
+    fn malformed_transition_lines_are_reported() {
+        let header = "alphabet={0,1}\nstate={q0,q1}\nstart_state=q0\n";
+        let error = DFA::from_string(&format!("{}(q0, 1)\n", header))
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MissingArrow { line: 4, ref text } if text == "(q0, 1)"
+        ));
+
+        let error = DFA::from_string(&format!("{}(q0)->q1\n", header))
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MalformedTransition { line: 4, ref text } if text == "(q0)->q1"
+        ));
+    }
+
+    #[test]
+    fn trim_removes_unreachable_and_dead_states() {
+        // u es inalcanzable y t es un estado muerto
+        let dfa = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b,t,u}\nstart_state=a\nF={b}\n(a,0)->t\n(a,1)->b\n(b,0)->a\n(b,1)->b\n(t,0)->t\n(t,1)->t\n(u,0)->a\n(u,1)->b",
+        )
+        .unwrap();
+        assert_eq!(
+            dfa.reachable_states(),
+            ["a", "b", "t"]
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
+        );
+
+        let reachable = dfa.trim(false);
+        assert_eq!(reachable.format_states(), "{a, b, t}");
+        assert_eq!(reachable.transition("a", '0').as_deref(), Some("t"));
+
+        let trimmed = dfa.trim(true);
+        assert_eq!(trimmed.format_states(), "{a, b}");
+        assert_eq!(trimmed.transition("a", '0'), None);
+
+        for word in words(&['0', '1'], 6) {
+            assert_eq!(reachable.run(&word), dfa.run(&word), "{}", word);
+            assert_eq!(trimmed.run(&word), dfa.run(&word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn run_result_separates_invalid_symbols_from_missing_transitions() {
+        let partial =
+            DFA::from_string("alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b")
+                .unwrap();
+        assert_eq!(partial.run_result("1"), RunResult::Accepted);
+        // `0` pertenece al alfabeto pero a no tiene transición con él
+        assert_eq!(partial.run_result("0"), RunResult::Rejected);
+        assert_eq!(partial.run_result("12"), RunResult::InvalidSymbol('2'));
+        assert_eq!(
+            partial.run_trace("0").rejection_message().as_deref(),
+            Some("No hay transición para el símbolo 0")
+        );
+    }
+
+    #[test]
+    fn builder_validates_the_automaton() {
+        let dfa = DfaBuilder::new()
+            .add_state("a", false)
+            .add_state("b", true)
+            .set_start("a")
+            .add_transition("a", '0', "a")
+            .add_transition("a", '1', "b")
+            .add_transition("b", '0', "a")
+            .add_transition("b", '1', "b")
+            .build()
+            .unwrap();
+        assert!(dfa.equivalent(&ends_in_one()));
+        assert_eq!(dfa.alphabet, ['0', '1']);
+
+        let missing_start = DfaBuilder::new().add_state("a", true).build();
+        assert!(matches!(missing_start, Err(DfaError::MissingStartState)));
+
+        let unknown_target = DfaBuilder::new()
+            .add_state("a", true)
+            .set_start("a")
+            .add_transition("a", '0', "z")
+            .build();
+        assert!(matches!(unknown_target, Err(DfaError::UnknownState(ref name)) if name == "z"));
+    }
+
+    #[test]
+    fn token_dfa_parses_and_runs() {
+        let dfa = DFA::from_token_string(
+            "alphabet={if, else, \"a,b\"}\nstate={s,t}\nstart_state=s\nF={t}\n(s, if)->t\n(t, else)->s\n(t, \"a,b\")->t",
+        )
+        .unwrap();
+        assert_eq!(dfa.alphabet, ["a,b", "else", "if"]);
+        assert!(dfa.run_tokens(&["if"]));
+        assert!(dfa.run_tokens(&["if", "a,b", "else", "if"]));
+        assert!(!dfa.run_tokens(&["if", "else"]));
+        assert!(!dfa.run_tokens(&["while"]));
+
+        let error = DFA::from_token_string("alphabet={if}\nstate={s}\nstart_state=s\n(s, a,b)->s")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::MalformedSymbol { line: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn token_dfa_round_trips_through_text() {
+        let mut builder = DfaBuilder::<String>::default();
+        builder.add_state("s", true).set_start("s");
+        for token in ["if", "while", "a,b", "{x}", "f(x)", "else if"] {
+            builder.add_transition("s", token.to_string(), "s");
+        }
+        let dfa = builder.build().unwrap();
+
+        let text = dfa.to_string_format();
+        assert!(text.starts_with("alphabet={\"a,b\",\"else if\",\"f(x)\",if,while,\"{x}\"}\n"));
+        assert!(text.contains("(s, \"a,b\")->s\n"));
+        let back = DFA::from_token_string(&text).unwrap();
+        assert_eq!(back.alphabet, dfa.alphabet);
+        assert_eq!(back.to_string_format(), text);
+        assert!(back.run_tokens(&["a,b", "{x}", "f(x)", "else if", "while"]));
+
+        // Los alfabetos de caracteres se siguen escribiendo sin comillas
+        let parens = DFA::from_string(
+            "alphabet={(,)}\nstate={s}\nstart_state=s\nF={s}\n(s, ()->s\n(s, ))->s",
+        )
+        .unwrap();
+        assert_eq!(
+            DFA::from_string(&parens.to_string_format())
+                .unwrap()
+                .alphabet,
+            ['(', ')']
+        );
+    }
+
+    #[test]
+    fn unrecognized_lines_are_rejected() {
+        let error = DFA::from_string("alphabet={0,1}\nstate={q0}\nstait=q0\n")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::UnrecognizedLine { line: 3, ref text } if text == "stait=q0"
+        ));
+        assert_eq!(error.to_string(), "Línea 3: no se reconoce \"stait=q0\"");
+    }
+
+    #[test]
+    fn comments_and_blank_lines_are_skipped() {
+        let dfa = DFA::from_string(
+            "# Palabras que terminan en 1\n\nalphabet={0,1} # binario\nstate={a,b}\n   # estados\nstart_state=a\nF={b}   \n(a,0)->a\n(a,1)->b # lee un 1\n(b,0)->a\n(b,1)->b\n",
+        )
+        .unwrap();
+        assert!(dfa.equivalent(&ends_in_one()));
+        assert_eq!(dfa.alphabet, ['0', '1']);
+
+        // Un `#` pegado a otros caracteres es un símbolo, no un comentario
+        let hash = DFA::from_string(
+            "alphabet={a, #}\nstate={q0,q1}\nstart_state=q0\nF={q1}\n(q0, #)->q1 # comentario",
+        )
+        .unwrap();
+        assert_eq!(hash.alphabet, ['#', 'a']);
+        assert!(hash.run("#"));
+    }
+
+    const JFLAP_ENDS_IN_ONE: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?><!--Created with JFLAP 7.1.-->
+<structure>
+    <type>fa</type>
+    <automaton>
+        <!--The list of states.-->
+        <state id="0" name="q0">
+            <x>60.0</x>
+            <y>80.0</y>
+            <initial/>
+        </state>
+        <state id="1" name="q1">
+            <x>180.0</x>
+            <y>80.0</y>
+            <final/>
+        </state>
+        <!--The list of transitions.-->
+        <transition><from>0</from><to>0</to><read>0</read></transition>
+        <transition><from>0</from><to>1</to><read>1</read></transition>
+        <transition><from>1</from><to>0</to><read>0</read></transition>
+        <transition><from>1</from><to>1</to><read>1</read></transition>
+    </automaton>
+</structure>
+"#;
+
+    #[test]
+    fn jflap_import_reads_a_minimal_file() {
+        let dfa = DFA::from_jflap(JFLAP_ENDS_IN_ONE).unwrap();
+        assert_eq!(dfa.format_states(), "{q0, q1}");
+        assert_eq!(dfa.format_start_state(), "q0");
+        assert_eq!(dfa.format_accept_states(), "{q1}");
+        assert!(dfa.equivalent(&ends_in_one()));
+
+        // Sin `<automaton>` ni atributo `name` los estados se nombran con su id
+        let flat = DFA::from_jflap(
+            "<structure><type>fa</type><state id=\"a\"><initial/><final/></state><transition><from>a</from><to>a</to><read>&lt;</read></transition></structure>",
+        )
+        .unwrap();
+        assert!(flat.run("<<"));
+    }
+
+    #[test]
+    fn jflap_import_rejects_invalid_automata() {
+        let epsilon = JFLAP_ENDS_IN_ONE.replace(
+            "<read>1</read></transition>\n        <transition><from>1</from><to>0</to>",
+            "<read/></transition>\n        <transition><from>1</from><to>0</to>",
+        );
+        assert!(matches!(
+            DFA::from_jflap(&epsilon),
+            Err(DfaError::EpsilonTransition { ref from, ref to }) if from == "q0" && to == "q1"
+        ));
+
+        let two_starts = JFLAP_ENDS_IN_ONE.replace("<final/>", "<initial/><final/>");
+        assert!(matches!(
+            DFA::from_jflap(&two_starts),
+            Err(DfaError::MalformedXml(_))
+        ));
+
+        let unknown_to = JFLAP_ENDS_IN_ONE.replace("<to>1</to>", "<to>7</to>");
+        assert!(matches!(
+            DFA::from_jflap(&unknown_to),
+            Err(DfaError::UnknownState(ref id)) if id == "7"
+        ));
+        let unknown_from = JFLAP_ENDS_IN_ONE.replace("<from>1</from>", "<from>9</from>");
+        assert!(matches!(
+            DFA::from_jflap(&unknown_from),
+            Err(DfaError::UnknownState(ref id)) if id == "9"
+        ));
+
+        let pushdown = JFLAP_ENDS_IN_ONE.replace("<type>fa</type>", "<type>pda</type>");
+        assert!(matches!(
+            DFA::from_jflap(&pushdown),
+            Err(DfaError::MalformedXml(_))
+        ));
+    }
+
+    #[test]
+    fn jflap_import_of_truncated_xml_is_an_error() {
+        let document = JFLAP_ENDS_IN_ONE.trim_end();
+        for end in (0..document.len()).filter(|&end| document.is_char_boundary(end)) {
+            assert!(
+                matches!(
+                    DFA::from_jflap(&document[..end]),
+                    Err(DfaError::MalformedXml(_))
+                ),
+                "{}",
+                &document[..end]
+            );
+        }
+        assert!(matches!(
+            DFA::from_jflap("<structure><state id=\"0\"></structure>"),
+            Err(DfaError::MalformedXml(_))
+        ));
+    }
+
+    #[test]
+    fn counterexample_finds_shortest_distinguishing_word() {
+        let regex = DFA::from_regex("(0|1)*1").unwrap();
+        assert_eq!(ends_in_one().counterexample(&regex), None);
+        assert!(ends_in_one().symmetric_difference(&regex).is_empty());
+
+        // "1" es la palabra más corta que separa ambos lenguajes
+        let word = ends_in_one().counterexample(&even_length()).unwrap();
+        assert_eq!(word, "");
+        let word = ends_in_one().counterexample(&sample()).unwrap();
+        assert_eq!(word, "1");
+        assert_ne!(ends_in_one().run(&word), sample().run(&word));
+        assert!(words(&['0', '1'], 0)
+            .iter()
+            .all(|w| ends_in_one().run(w) == sample().run(w)));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_accepted_words_fit_the_budget() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let dfa = sample();
+        for max_len in 0..8 {
+            for _ in 0..50 {
+                match dfa.random_accepted_word(&mut rng, max_len) {
+                    Some(word) => {
+                        assert!(dfa.run(&word), "{}", word);
+                        assert!(word.chars().count() <= max_len, "{}", word);
+                    }
+                    // La palabra aceptada más corta es "10"
+                    None => assert!(max_len < 2),
+                }
+            }
+        }
+
+        let empty = DFA::from_string("alphabet={0,1}\nstate={a}\nstart_state=a\n(a,0)->a").unwrap();
+        assert_eq!(empty.random_accepted_word(&mut rng, 10), None);
+    }
+
+    #[test]
+    fn pumping_length_ignores_the_trap_state() {
+        let partial = DFA::from_string(
+            "alphabet={0,1}\nstate={a,b}\nstart_state=a\nF={b}\n(a,1)->b\n(b,1)->b",
+        )
+        .unwrap();
+        assert_eq!(partial.pumping_length(), 2);
+        assert_eq!(partial.complete_with_trap().pumping_length(), 2);
+        assert_eq!(sample().pumping_length(), 3);
+        assert_eq!(
+            sample().pumping_length(),
+            sample().trim(true).complete_with_trap().pumping_length()
+        );
+    }
+
+    #[test]
+    fn explicit_transitions_override_wildcards() {
+        // La línea explícita gana aunque esté antes o después del comodín
+        for lines in ["(q0, *)->q9\n(q0, b)->q1", "(q0, b)->q1\n(q0, *)->q9"] {
+            let dfa = DFA::from_string(&format!(
+                "alphabet={{a,b,c}}\nstate={{q0,q1,q9}}\nstart_state=q0\nF={{q1}}\n{}",
+                lines
+            ))
+            .unwrap();
+            assert_eq!(dfa.transition("q0", 'b').as_deref(), Some("q1"));
+            assert_eq!(dfa.transition("q0", 'a').as_deref(), Some("q9"));
+            assert_eq!(dfa.transition("q0", 'c').as_deref(), Some("q9"));
+        }
+
+        // Un comodín no reemplaza una transición explícita repetida
+        let error = DFA::from_string(
+            "alphabet={a,b}\nstate={q0,q1}\nstart_state=q0\n(q0, *)->q0\n(q0, a)->q1\n(q0, a)->q0",
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(
+            error,
+            DfaParseError::DuplicateTransition { line: 6, .. }
+        ));
+    }
+}
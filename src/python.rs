@@ -0,0 +1,87 @@
+// Clase de Python que envuelve al autómata, generada con PyO3.
+// Los errores de lectura se lanzan como `ValueError` con el mismo mensaje que muestra `Display`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::DFA;
+
+// Autómata de caracteres expuesto a Python como `automata.Dfa`.
+#[pyclass(name = "Dfa")]
+pub struct PyDfa {
+    dfa: DFA,
+}
+
+#[pymethods]
+impl PyDfa {
+    /**
+     Lee un autómata en el formato de texto de `DFA::from_string`.
+        # Arguments
+        * `text` - La descripción del autómata.
+        # Returns
+        Retorna el autómata, o lanza `ValueError` si la descripción no es válida.
+    */
+    #[staticmethod]
+    pub fn parse(text: &str) -> PyResult<PyDfa> {
+        DFA::from_string(text)
+            .map(|dfa| PyDfa { dfa })
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    // Valida si la palabra es aceptada por el autómata
+    pub fn run(&self, word: &str) -> bool {
+        self.dfa.run(word)
+    }
+
+    // Retorna el autómata mínimo equivalente
+    pub fn minimize(&self) -> PyDfa {
+        PyDfa {
+            dfa: self.dfa.minimize(),
+        }
+    }
+
+    // Retorna la representación en DOT de Graphviz
+    pub fn to_dot(&self) -> String {
+        self.dfa.to_dot()
+    }
+
+    // Retorna las palabras aceptadas de longitud menor o igual a `max_len`
+    pub fn accepted_words(&self, max_len: usize) -> Vec<String> {
+        self.dfa.accepted_words(max_len)
+    }
+
+    // Muestra la 5-tupla al imprimir el objeto en Python
+    pub fn __str__(&self) -> String {
+        self.dfa.to_string()
+    }
+}
+
+// Módulo `automata` de Python
+#[pymodule]
+fn automata(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDfa>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENDS_IN_ONE: &str = "alphabet={0,1}\nstate={a,b,c}\nstart_state=a\nF={b,c}\n(a,0)->a\n(a,1)->b\n(b,0)->a\n(b,1)->c\n(c,0)->a\n(c,1)->c";
+
+    #[test]
+    fn wrappers_delegate_to_dfa() {
+        let dfa = PyDfa::parse(ENDS_IN_ONE).unwrap();
+        assert!(dfa.run("0011"));
+        assert!(!dfa.run("10"));
+        assert_eq!(dfa.accepted_words(2), ["1", "01", "11"]);
+        assert_eq!(
+            dfa.__str__(),
+            DFA::from_string(ENDS_IN_ONE).unwrap().to_string()
+        );
+
+        let minimal = dfa.minimize();
+        assert_eq!(minimal.accepted_words(3), dfa.accepted_words(3));
+        assert!(minimal.to_dot().starts_with("digraph"));
+        assert!(PyDfa::parse("alphabet={0}\nstate={a}\n(a,0)->b").is_err());
+    }
+}
@@ -0,0 +1,43 @@
+// Envoltorio del autómata para usarlo desde JavaScript con `wasm-bindgen`.
+// Los errores se convierten en cadenas de JavaScript con el mismo mensaje que muestra `Display`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::DFA;
+
+// Autómata de caracteres expuesto a JavaScript.
+#[wasm_bindgen]
+pub struct WasmDfa {
+    dfa: DFA,
+}
+
+#[wasm_bindgen]
+impl WasmDfa {
+    /**
+     Lee un autómata en el formato de texto de `DFA::from_string`.
+        # Arguments
+        * `text` - La descripción del autómata.
+        # Returns
+        Retorna el autómata, o el mensaje de error como cadena de JavaScript.
+    */
+    pub fn parse(text: &str) -> Result<WasmDfa, JsValue> {
+        DFA::from_string(text)
+            .map(|dfa| WasmDfa { dfa })
+            .map_err(|error| JsValue::from_str(&error.to_string()))
+    }
+
+    // Valida si la palabra es aceptada por el autómata
+    pub fn run(&self, word: &str) -> bool {
+        self.dfa.run(word)
+    }
+
+    // Retorna la representación en DOT de Graphviz
+    pub fn to_dot(&self) -> String {
+        self.dfa.to_dot()
+    }
+
+    // Retorna la 5-tupla seguida de las transiciones, una por línea
+    pub fn describe(&self) -> String {
+        format!("{}\n{}", self.dfa, self.dfa.format_transitions())
+    }
+}
@@ -0,0 +1,282 @@
+// Lector mínimo de XML, suficiente para los archivos que generan herramientas como JFLAP.
+// Ignora la declaración `<?xml ...?>`, los comentarios y las declaraciones `<!...>`; no valida
+// contra ningún esquema.
+
+// Un elemento XML con sus atributos, sus hijos y el texto que contiene directamente.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<Element>,
+    pub(crate) text: String,
+}
+
+impl Element {
+    // Retorna el valor de un atributo
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // Retorna el primer hijo con el nombre dado
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    // Retorna todos los hijos con el nombre dado
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/**
+ Lee un documento XML y retorna su elemento raíz.
+    # Arguments
+    * `text` - El documento completo.
+    # Returns
+    Retorna el elemento raíz o un mensaje que describe el primer error encontrado.
+*/
+pub(crate) fn parse(text: &str) -> Result<Element, String> {
+    let mut parser = Parser { text, position: 0 };
+    parser.skip_misc()?;
+    let root = parser.parse_element()?;
+    parser.skip_misc()?;
+    if parser.position < text.len() {
+        return Err("Contenido después del elemento raíz".to_string());
+    }
+    Ok(root)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.text[self.position..]
+    }
+
+    // Avanza hasta después de `end`, o retorna un error si no aparece
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(offset) => {
+                self.position += offset + end.len();
+                Ok(())
+            }
+            None => Err(format!("Falta \"{}\"", end)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.position = self.text.len() - trimmed.len();
+    }
+
+    // Salta espacios, comentarios, instrucciones de procesamiento y declaraciones
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    // Lee un nombre de elemento o de atributo
+    fn parse_name(&mut self) -> Result<String, String> {
+        let length = self
+            .rest()
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(self.rest().len());
+        if length == 0 {
+            return Err(format!(
+                "Se esperaba un nombre en la posición {}",
+                self.position
+            ));
+        }
+        let name = self.rest()[..length].to_string();
+        self.position += length;
+        Ok(name)
+    }
+
+    fn parse_element(&mut self) -> Result<Element, String> {
+        if !self.rest().starts_with('<') {
+            return Err(format!(
+                "Se esperaba un elemento en la posición {}",
+                self.position
+            ));
+        }
+        self.position += 1;
+        let mut element = Element {
+            name: self.parse_name()?,
+            attributes: Vec::new(),
+            children: Vec::new(),
+            text: String::new(),
+        };
+
+        // Atributos hasta `>` o `/>`
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let key = self.parse_name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(format!("Falta el valor del atributo \"{}\"", key));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(format!("El atributo \"{}\" no está entre comillas", key)),
+            };
+            self.position += 1;
+            let length = self
+                .rest()
+                .find(quote)
+                .ok_or_else(|| format!("El atributo \"{}\" no se cierra", key))?;
+            let value = decode_entities(&self.rest()[..length]);
+            self.position += length + 1;
+            element.attributes.push((key, value));
+        }
+
+        // Contenido hasta la etiqueta de cierre
+        loop {
+            if self.rest().starts_with("</") {
+                self.position += 2;
+                let name = self.parse_name()?;
+                if name != element.name {
+                    return Err(format!(
+                        "Se esperaba </{}> pero se encontró </{}>",
+                        element.name, name
+                    ));
+                }
+                self.skip_whitespace();
+                self.skip_past(">")?;
+                element.text = element.text.trim().to_string();
+                return Ok(element);
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                let length = self
+                    .rest()
+                    .find("]]>")
+                    .ok_or_else(|| "Falta \"]]>\"".to_string())?;
+                element.text.push_str(&self.rest()[..length]);
+                self.position += length + 3;
+            } else if self.rest().starts_with('<') {
+                let child = self.parse_element()?;
+                element.children.push(child);
+            } else if self.rest().is_empty() {
+                return Err(format!("Falta </{}>", element.name));
+            } else {
+                let length = self.rest().find('<').unwrap_or(self.rest().len());
+                element
+                    .text
+                    .push_str(&decode_entities(&self.rest()[..length]));
+                self.position += length;
+            }
+        }
+    }
+}
+
+// Reemplaza las entidades predefinidas y las referencias numéricas por sus caracteres
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let replacement = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match replacement {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            // Un `&` que no forma una entidad se conserva tal cual
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_entities_cdata_and_comments() {
+        let root = parse(
+            "<?xml version=\"1.0\"?>\n<!DOCTYPE x>\n<!-- inicio -->\n<a k='1 &amp; 2'>\n  <!-- nota -->\n  <b>x &lt; y &#65;&#x42;</b>\n  <c><![CDATA[<no es> & etiqueta]]></c>\n  <d/>\n  <e>&desconocida;</e>\n</a>\n",
+        )
+        .unwrap();
+        assert_eq!(root.attribute("k"), Some("1 & 2"));
+        assert_eq!(root.child("b").unwrap().text, "x < y AB");
+        assert_eq!(root.child("c").unwrap().text, "<no es> & etiqueta");
+        assert_eq!(root.child("d").unwrap().text, "");
+        assert_eq!(root.child("e").unwrap().text, "&desconocida;");
+        assert_eq!(root.children.len(), 4);
+    }
+
+    #[test]
+    fn nested_elements_with_the_same_name() {
+        let root = parse("<s><s><s>x</s></s><t/></s>").unwrap();
+        assert_eq!(root.children_named("s").count(), 1);
+        assert_eq!(root.child("s").unwrap().child("s").unwrap().text, "x");
+        assert!(root.child("t").is_some());
+    }
+
+    #[test]
+    fn malformed_documents_are_errors() {
+        assert_eq!(
+            parse("<a></b>").unwrap_err(),
+            "Se esperaba </a> pero se encontró </b>"
+        );
+        assert_eq!(parse("<a>").unwrap_err(), "Falta </a>");
+        assert!(parse("<a k=1/>").is_err());
+        assert!(parse("<a k=\"1/>").is_err());
+        assert!(parse("<a/><b/>").is_err());
+        assert!(parse("<a><![CDATA[x</a>").is_err());
+        assert!(parse("<!-- sin cerrar").is_err());
+        assert!(parse("").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+// Pruebas de la línea de comandos: ejecutan el binario `afd` y revisan su salida y su código de salida.
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+// Palabras sobre {0,1} que terminan en 1
+const ENDS_IN_ONE: &str = "alphabet={0,1}
+state={a,b}
+start_state=a
+F={b}
+(a,0)->a
+(a,1)->b
+(b,0)->a
+(b,1)->b
+";
+
+// Escribe `content` en un archivo temporal propio de esta prueba y retorna su ruta
+fn temp_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("afd-cli-{}-{}", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+// Ejecuta `afd` con los argumentos dados
+fn afd(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_afd"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn word_result_is_only_reported_by_exit_code() {
+    let dfa = temp_file("word.dfa", ENDS_IN_ONE);
+    let dfa = dfa.to_str().unwrap();
+
+    let accepted = afd(&["--dfa", dfa, "--word", "0101"]);
+    assert_eq!(accepted.status.code(), Some(0));
+    assert_eq!(stdout(&accepted), "");
+
+    let rejected = afd(&["--dfa", dfa, "--word", "10"]);
+    assert_eq!(rejected.status.code(), Some(1));
+    assert_eq!(stdout(&rejected), "");
+
+    let verbose = afd(&["--dfa", dfa, "--word", "10", "--verbose"]);
+    assert_eq!(verbose.status.code(), Some(1));
+    assert!(stdout(&verbose).ends_with("REJECT\t10\n"));
+}
+
+#[test]
+fn bad_arguments_print_usage_to_stderr() {
+    let output = afd(&["--dfa", "x.dfa", "--wrod", "1"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).starts_with("Argumento desconocido \"--wrod\""));
+    assert!(stderr(&output).contains("Uso:"));
+
+    let missing_dfa = afd(&["--word", "1"]);
+    assert_eq!(missing_dfa.status.code(), Some(2));
+    assert!(stderr(&missing_dfa).contains("Falta la ruta del autómata (--dfa)"));
+}
+
+#[test]
+fn unreadable_automaton_exits_with_error() {
+    let missing = afd(&["--dfa", "/no/existe.dfa", "--word", "1"]);
+    assert_eq!(missing.status.code(), Some(2));
+    assert!(stderr(&missing).starts_with("Error al leer el archivo"));
+
+    let invalid = temp_file("invalid.dfa", "alphabet={0}\nstait=a\n");
+    let invalid = afd(&["--dfa", invalid.to_str().unwrap(), "--word", "0"]);
+    assert_eq!(invalid.status.code(), Some(2));
+    assert!(stderr(&invalid).starts_with("Error al leer el autómata"));
+}
+
+#[test]
+fn words_file_reports_each_word() {
+    let dfa = temp_file("words.dfa", ENDS_IN_ONE);
+    let dfa = dfa.to_str().unwrap();
+
+    let all_accepted = temp_file("accepted.txt", "1\n0101\n  11  \n");
+    let output = afd(&["--dfa", dfa, "--words-file", all_accepted.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "ACCEPT\t1\nACCEPT\t0101\nACCEPT\t11\n");
+
+    let with_rejected = temp_file("rejected.txt", "1\n10\n011\n");
+    let output = afd(&[
+        "--dfa",
+        dfa,
+        "--words-file",
+        with_rejected.to_str().unwrap(),
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "ACCEPT\t1\nREJECT\t10\nACCEPT\t011\n");
+
+    let output = afd(&["--dfa", dfa, "--words-file", "/no/existe.txt"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("Error al leer el archivo \"/no/existe.txt\""));
+}
@@ -0,0 +1,104 @@
+// Verifica con autómatas y palabras generados al azar que `DFA::run`, el autómata mínimo y
+// `CompiledDfa::run` siempre coinciden. Se ejecuta con `cargo test`.
+
+use automata::DFA;
+
+const AUTOMATA: usize = 500;
+const WORDS_PER_AUTOMATON: usize = 50;
+const MAX_STATES: usize = 6;
+const MAX_WORD_LENGTH: usize = 12;
+const SYMBOLS: [char; 3] = ['a', 'b', 'c'];
+
+// Generador xorshift con semilla fija, para que una falla se pueda reproducir
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // Retorna un número en `0..bound`
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+// Genera la descripción textual de un autómata; una de cada cinco transiciones se omite para
+// ejercitar también los autómatas parciales
+fn random_dfa(rng: &mut Rng) -> String {
+    let symbols = &SYMBOLS[..1 + rng.below(SYMBOLS.len())];
+    let states: Vec<String> = (0..1 + rng.below(MAX_STATES))
+        .map(|i| format!("q{}", i))
+        .collect();
+    let accepting: Vec<&str> = states
+        .iter()
+        .filter(|_| rng.below(2) == 0)
+        .map(|state| state.as_str())
+        .collect();
+
+    let symbol_list: Vec<String> = symbols.iter().map(|symbol| symbol.to_string()).collect();
+    let mut text = format!(
+        "alphabet={{{}}}\nstate={{{}}}\nstart_state=q0\nF={{{}}}\n",
+        symbol_list.join(","),
+        states.join(","),
+        accepting.join(",")
+    );
+    for state in &states {
+        for symbol in symbols {
+            if rng.below(5) != 0 {
+                let target = &states[rng.below(states.len())];
+                text.push_str(&format!("({}, {})->{}\n", state, symbol, target));
+            }
+        }
+    }
+    text
+}
+
+// Genera una palabra que a veces incluye un símbolo fuera del alfabeto
+fn random_word(rng: &mut Rng) -> String {
+    (0..rng.below(MAX_WORD_LENGTH + 1))
+        .map(|_| match rng.below(20) {
+            0 => 'z',
+            _ => SYMBOLS[rng.below(SYMBOLS.len())],
+        })
+        .collect()
+}
+
+fn main() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+    for _ in 0..AUTOMATA {
+        let text = random_dfa(&mut rng);
+        let dfa = DFA::from_string(&text).expect("el autómata generado es válido");
+        let minimized = dfa.minimize();
+        let compiled = dfa.compile();
+
+        for _ in 0..WORDS_PER_AUTOMATON {
+            let word = random_word(&mut rng);
+            let expected = dfa.run(&word);
+            assert_eq!(
+                minimized.run(&word),
+                expected,
+                "el autómata mínimo no coincide con \"{}\" en:\n{}",
+                word,
+                text
+            );
+            assert_eq!(
+                compiled.run(&word),
+                expected,
+                "CompiledDfa no coincide con \"{}\" en:\n{}",
+                word,
+                text
+            );
+        }
+    }
+
+    println!(
+        "{} autómatas y {} palabras verificados",
+        AUTOMATA,
+        AUTOMATA * WORDS_PER_AUTOMATON
+    );
+}